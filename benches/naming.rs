@@ -0,0 +1,39 @@
+//
+// IMPORTS
+//
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use danske_tal::{DanishCache, DanishCompoundNumeral};
+
+//
+// BENCHMARKS
+//
+
+// Runs the closure a fixed number of times and prints the average time per run
+// There is no harness, so run with cargo bench and read the numbers off the output
+fn bench(name: &str, iterations: u32, mut f: impl FnMut()) {
+  for _ in 0..iterations / 10 { f(); } // Warm up
+
+  let start = Instant::now();
+  for _ in 0..iterations { f(); }
+  let per_run = start.elapsed() / iterations;
+  println!("{name:<40} {per_run:>10.2?} per run");
+}
+
+fn main() {
+  // Numbers below 20 take the small_name fast path, and the compounds up to 99 are the nearest case without it
+  bench("small numbers, 0 to 19 (20 calls)", 1_000_000, || { for n in 0..20_i128 { black_box(black_box(n).danish_compound_numeral_name()); } });
+  bench("compounds, 20 to 39 (20 calls)", 1_000_000, || { for n in 20..40_i128 { black_box(black_box(n).danish_compound_numeral_name()); } });
+
+  // The worst case is the number with the most thousands' groups, all of them nonzero
+  bench("worst case, i128::MAX", 100_000, || { black_box(black_box(i128::MAX).danish_compound_numeral_name()); });
+  bench("worst case, i128::MIN", 100_000, || { black_box(black_box(i128::MIN).danish_compound_numeral_name()); });
+
+  // Naming the same few numbers over and over, with and without DanishCache remembering them
+  let hot = [7_i128, 42, 1_234, 1_000_000, 987_654_321];
+  bench("uncached, 5 hot numbers", 1_000_000, || { for n in hot { black_box(black_box(n).danish_compound_numeral_name()); } });
+  let mut cache = DanishCache::new();
+  bench("cached, 5 hot numbers", 1_000_000, || { for n in hot { black_box(cache.get_or_convert(black_box(n))); } });
+}
@@ -0,0 +1,138 @@
+//
+// IMPORTS
+//
+
+use crate::{danish_ordinal_name, DanishCompoundNumeral, DanishError};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+// The Danish names of the letters of the alphabet
+const LETTER_NAMES: &[(char, &str)] = &[
+  ('a', "a"), ('b', "be"), ('c', "ce"), ('d', "de"), ('e', "e"), ('f', "ef"), ('g', "ge"), ('h', "hå"),
+  ('i', "i"), ('j', "jod"), ('k', "kå"), ('l', "el"), ('m', "em"), ('n', "en"), ('o', "o"), ('p', "pe"),
+  ('q', "ku"), ('r', "er"), ('s', "es"), ('t', "te"), ('u', "u"), ('v', "ve"), ('w', "dobbelt-ve"), ('x', "eks"),
+  ('y', "y"), ('z', "sæt"), ('æ', "æ"), ('ø', "ø"), ('å', "å")
+];
+
+const GROUND_FLOOR: &str = "stuen";
+const BASEMENT: &str = "kælderen";
+const FLOOR: &str = "sal";
+const DOOR: &str = "dør";
+
+// The usual abbreviations for the side of the landing the door is on
+const DOOR_SIDES: &[(&str, &str)] = &[("th", "til højre"), ("tv", "til venstre"), ("mf", "midtfor")];
+
+//
+// HOUSE NUMBERS
+//
+
+// Returns the Danish name of a letter, or the letter itself in upper case when it is not spelled
+// Only the letters of the Danish alphabet are read, as others like "ß" and "é" have no name the reader would know
+fn letter_name(letter: char, spell: bool) -> Result<String, DanishError> {
+  let lower = letter.to_lowercase().next().unwrap_or(letter);
+  match LETTER_NAMES.iter().find(|(c, _)| *c == lower) {
+    Some((_, name)) if spell => Ok(name.to_string()),
+    Some(..) => Ok(letter.to_uppercase().collect()),
+    None => Err(DanishError::InvalidDigit(letter))
+  }
+}
+
+// Returns the Danish reading of a house number with an optional letter, "12B" -> "tolv B"
+// With spell_letter the letter is read by its name instead - "12B" -> "tolv be"
+pub fn danish_house_number(s: &str, spell_letter: bool) -> Result<String, DanishError> {
+  let s = s.trim();
+  let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+  let (digits, rest) = s.split_at(split);
+
+  // There must be a number, and at most a single letter after it
+  if digits.is_empty() {
+    return Err(s.chars().next().map_or(DanishError::EmptyInput, DanishError::InvalidDigit));
+  }
+  let number = digits.parse::<i128>().map_err(|_| DanishError::too_large(digits))?.danish_compound_numeral_name();
+
+  let mut letters = rest.trim_start().chars();
+  match (letters.next(), letters.next()) {
+    (None, _) => Ok(number),
+    (Some(letter), None) if letter.is_alphabetic() => Ok(format!("{number} {}", letter_name(letter, spell_letter)?)),
+    (Some(c), None) => Err(DanishError::InvalidDigit(c)),
+    (Some(_), Some(c)) => Err(DanishError::InvalidDigit(c))
+  }
+}
+
+//
+// FLOORS AND DOORS
+//
+
+// Returns the Danish reading of the floor and door of an apartment, 3 and "2" -> "tredje sal, dør to"
+// Floor 0 is the ground floor "stuen" and floor -1 the basement "kælderen"
+// The door may be a number, a letter, one of "th", "tv" and "mf", or empty for no door
+pub fn danish_floor_door(floor: i128, door: &str) -> Result<String, DanishError> {
+  let floor = match floor {
+    0 => GROUND_FLOOR.to_string(),
+    -1 => BASEMENT.to_string(),
+    ..-1 => return Err(DanishError::OutOfRange),
+    floor => format!("{} {FLOOR}", danish_ordinal_name(floor))
+  };
+
+  let door = door.trim().trim_end_matches('.');
+  if door.is_empty() { return Ok(floor); }
+  if let Some((_, side)) = DOOR_SIDES.iter().find(|(abbreviation, _)| door.eq_ignore_ascii_case(abbreviation)) {
+    return Ok(format!("{floor} {side}"));
+  }
+
+  let mut letters = door.chars();
+  let door = match (letters.next(), letters.next()) {
+    (Some(letter), None) if letter.is_alphabetic() => letter_name(letter, true)?,
+    _ if door.chars().all(|c| c.is_ascii_digit()) => door.parse::<i128>().map_err(|_| DanishError::too_large(door))?.danish_compound_numeral_name(),
+    _ => return Err(DanishError::InvalidDigit(door.chars().find(|c| !c.is_ascii_digit()).unwrap_or(' ')))
+  };
+
+  Ok(format!("{floor}, {DOOR} {door}"))
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn house_numbers_read_the_number_and_the_letter() {
+    assert_eq!(danish_house_number("12B", false).unwrap(), "tolv B");
+    assert_eq!(danish_house_number("12 b", true).unwrap(), "tolv be");
+    assert_eq!(danish_house_number("7", false).unwrap(), "syv");
+  }
+
+  #[test]
+  fn house_numbers_need_a_number_and_at_most_one_letter() {
+    assert_eq!(danish_house_number("B12", false), Err(DanishError::InvalidDigit('B')));
+    assert_eq!(danish_house_number("", false), Err(DanishError::EmptyInput));
+    assert_eq!(danish_house_number("12BC", false), Err(DanishError::InvalidDigit('C')));
+  }
+
+  #[test]
+  fn letters_outside_the_danish_alphabet_are_invalid() {
+    assert_eq!(danish_house_number("12ß", true), Err(DanishError::InvalidDigit('ß')));
+    assert_eq!(danish_house_number("12ß", false), Err(DanishError::InvalidDigit('ß')));
+    assert_eq!(danish_floor_door(2, "é"), Err(DanishError::InvalidDigit('é')));
+    assert_eq!(danish_house_number("12æ", false).unwrap(), "tolv Æ");
+  }
+
+  #[test]
+  fn floors_are_ordinals_and_doors_cardinals() {
+    assert_eq!(danish_floor_door(3, "2").unwrap(), "tredje sal, dør to");
+    assert_eq!(danish_floor_door(2, "B").unwrap(), "anden sal, dør be");
+    assert_eq!(danish_floor_door(1, "th").unwrap(), "første sal til højre");
+  }
+
+  #[test]
+  fn ground_floor_and_basement_have_names() {
+    assert_eq!(danish_floor_door(0, "").unwrap(), "stuen");
+    assert_eq!(danish_floor_door(-1, "tv.").unwrap(), "kælderen til venstre");
+    assert_eq!(danish_floor_door(-2, ""), Err(DanishError::OutOfRange));
+  }
+}
@@ -0,0 +1,58 @@
+//
+// IMPORTS
+//
+
+use std::collections::HashMap;
+
+use crate::{DanishCompoundNumeral, DanishConfig};
+
+//
+// CACHE
+//
+
+// Remembers the names of the numbers it has named, for workloads that name the same few numbers over and over
+// Every name is kept until the cache is dropped, so it is meant for small sets of hot numbers
+#[derive(Debug, Clone, Default)]
+pub struct DanishCache {
+  config: DanishConfig,
+  names: HashMap<i128, String>
+}
+
+impl DanishCache {
+  pub fn new() -> Self { Self::default() }
+
+  // A cache for names spelled according to the given config
+  pub fn with_config(config: DanishConfig) -> Self {
+    DanishCache { config, names: HashMap::new() }
+  }
+
+  // Returns the name of a number, naming it only the first time it is asked for
+  pub fn get_or_convert(&mut self, number: i128) -> &str {
+    let config = &self.config;
+    self.names.entry(number).or_insert_with(|| number.danish_compound_numeral_name_with(config))
+  }
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn a_cache_hit_returns_the_remembered_name() {
+    let mut cache = DanishCache::new();
+    let first = cache.get_or_convert(1234).as_ptr();
+    let second = cache.get_or_convert(1234);
+    assert_eq!(second, "et tusind to hundrede og fireogtredive");
+    assert_eq!(second.as_ptr(), first);
+  }
+
+  #[test]
+  fn a_cache_names_with_its_config() {
+    let mut cache = DanishCache::with_config(DanishConfig::new().include_numeral(true));
+    assert_eq!(cache.get_or_convert(1234), 1234.danish_compound_numeral_name_with(&DanishConfig::new().include_numeral(true)));
+  }
+}
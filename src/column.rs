@@ -0,0 +1,45 @@
+//
+// IMPORTS
+//
+
+use crate::DanishCompoundNumeral;
+
+//
+// COLUMNS
+//
+
+// Which side of a column the names line up on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+  #[default]
+  Left, // Padded with spaces after the name
+  Right // Padded with spaces before the name, like numbers in a table
+}
+
+// Returns the Danish compound numeral names of a column of numbers, padded to the width of the longest
+// The width is counted in characters rather than bytes, so "én" takes up as much room as "en" on screen
+pub fn danish_column(numbers: &[i128], align: Align) -> Vec<String> {
+  let names: Vec<String> = numbers.iter().map(|number| number.danish_compound_numeral_name()).collect();
+  let width = names.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+
+  names.into_iter().map(|name| match align {
+    Align::Left => format!("{name:<width$}"),
+    Align::Right => format!("{name:>width$}")
+  }).collect()
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn columns_are_padded_to_the_longest_name() {
+    assert_eq!(danish_column(&[1, 21, 100], Align::Left), ["et         ", "enogtyve   ", "et hundrede"]);
+    assert_eq!(danish_column(&[1, 21, 100], Align::Right), ["         et", "   enogtyve", "et hundrede"]);
+    assert!(danish_column(&[], Align::Right).is_empty());
+  }
+}
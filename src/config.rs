@@ -0,0 +1,463 @@
+//
+// IMPORTS
+//
+
+use crate::{format_danish_grouped, DECIMAL_SEPARATOR, EMPH_NEUTER_ONE, EMPH_ONE, HUNDRED, NEUTER_ONE, NUMBER_NAMES};
+
+//
+// CONFIG
+//
+
+// Which form of "one" is used where one stands on its own, like 1, 101 or 1_000_001
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OneForm {
+  #[default]
+  Emphasised, // "et" for 1 on its own, and the emphasised "én" at the end of a compound - "et hundrede og én"
+  Neuter,     // Always "et" - "et hundrede og et"
+  Common      // Always "en" - "en" and "et hundrede og en"
+}
+
+impl OneForm {
+  // The form of one at the end of a compound
+  pub(crate) fn emphasised(self) -> &'static str {
+    match self {
+      OneForm::Emphasised => EMPH_ONE,
+      OneForm::Neuter => NEUTER_ONE,
+      OneForm::Common => NUMBER_NAMES[0][1]
+    }
+  }
+
+  // The form of 1 on its own
+  pub(crate) fn standalone(self) -> &'static str {
+    match self {
+      OneForm::Emphasised | OneForm::Neuter => NEUTER_ONE,
+      OneForm::Common => NUMBER_NAMES[0][1]
+    }
+  }
+}
+
+// How the decimals of a number are read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalMode {
+  #[default]
+  Digits, // Digit by digit - 3.25 -> "tre komma to, fem"
+  Whole,  // As one whole number after any leading zeros - 3.25 -> "tre komma femogtyve", 3.05 -> "tre komma nul fem"
+          // Only up to WHOLE_DECIMALS_LIMIT (two) decimals, longer decimals are read digit by digit - 3.125 -> "tre komma en, to, fem"
+  Pairs   // Two digits at a time, an odd last digit on its own - 0.2576 -> "nul komma femogtyve seksoghalvfjerds"
+}
+
+// The letter case of the whole name, applied once it is spelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+  #[default]
+  Lower,      // "syv millioner og én"
+  UpperFirst, // "Syv millioner og én"
+  Upper,      // "SYV MILLIONER OG ÉN"
+  TitleEach   // "Syv Millioner Og Én"
+}
+
+// Returns the word with its first letter in upper case
+fn upper_first(word: &str) -> String {
+  let mut chars = word.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new()
+  }
+}
+
+impl Case {
+  // Returns the name in this case. Names are spelled in lower case, so Lower leaves them be
+  pub(crate) fn apply(self, name: String) -> String {
+    match self {
+      Case::Lower => name,
+      Case::UpperFirst => upper_first(&name),
+      Case::Upper => name.to_uppercase(),
+      Case::TitleEach => name.split(' ').map(upper_first).collect::<Vec<String>>().join(" ")
+    }
+  }
+}
+
+// How the thousands' groups of a number written in digits are separated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupStyle {
+  #[default]
+  Dot,       // The Danish convention, "1.234.567"
+  ThinSpace, // A narrow no-break space (U+202F), as typeset text does it - "1 234 567"
+  None       // No separator, "1234567"
+}
+
+impl GroupStyle {
+  // What goes between the groups
+  pub(crate) fn separator(self) -> &'static str {
+    match self {
+      GroupStyle::Dot => ".",
+      GroupStyle::ThinSpace => "\u{202F}",
+      GroupStyle::None => ""
+    }
+  }
+}
+
+// Options for how numbers are spelled. The default spells numbers like danish_compound_numeral_name
+// Built like DanishConfig::new().hundred("hundred")
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanishConfig {
+  pub(crate) hundred: &'static str,
+  pub(crate) single_final_og: bool,
+  pub(crate) decimal_separator: &'static str,
+  pub(crate) hyphenate_compounds: bool,
+  pub(crate) decimal_mode: DecimalMode,
+  pub(crate) one_form: OneForm,
+  pub(crate) trim_trailing_zeros: bool,
+  pub(crate) group_separator: &'static str,
+  pub(crate) decimal_places: Option<usize>,
+  pub(crate) thousand: &'static str,
+  pub(crate) compound_thousands: bool,
+  pub(crate) case: Case,
+  pub(crate) always_explicit_one: bool,
+  pub(crate) suffix: Option<&'static str>,
+  pub(crate) include_numeral: bool,
+  pub(crate) half_idioms: bool,
+  pub(crate) numeral_grouping: GroupStyle
+}
+
+impl Default for DanishConfig {
+  fn default() -> Self {
+    DanishConfig {
+      hundred: HUNDRED,
+      single_final_og: false,
+      decimal_separator: DECIMAL_SEPARATOR,
+      hyphenate_compounds: false,
+      decimal_mode: DecimalMode::Digits,
+      one_form: OneForm::Emphasised,
+      trim_trailing_zeros: true,
+      group_separator: " ",
+      decimal_places: None,
+      thousand: NUMBER_NAMES[3][0],
+      compound_thousands: false,
+      case: Case::Lower,
+      always_explicit_one: false,
+      suffix: None,
+      include_numeral: false,
+      half_idioms: false,
+      numeral_grouping: GroupStyle::Dot
+    }
+  }
+}
+
+impl DanishConfig {
+  pub fn new() -> Self { Self::default() }
+
+  // The one in front of "hundrede" and the orders of magnitude, in the gender of the word after it
+  pub(crate) fn scale_one(&self, neuter: bool) -> &'static str {
+    match (self.always_explicit_one, neuter) {
+      (false, true) => NEUTER_ONE,
+      (false, false) => NUMBER_NAMES[0][1],
+      (true, true) => EMPH_NEUTER_ONE,
+      (true, false) => EMPH_ONE
+    }
+  }
+
+  // Finishes a whole name, with the suffix after everything else and the case applied to all of it
+  pub(crate) fn finish(&self, name: String) -> String {
+    self.case.apply(match self.suffix {
+      Some(suffix) => format!("{name} {suffix}"),
+      None => name
+    })
+  }
+
+  // The name of a number, followed by its numeral in parentheses if the config includes it
+  // The integer digits may have a minus sign, and the numeral is written with a decimal comma - "-1.234,5"
+  pub(crate) fn with_numeral(&self, name: String, integer: &str, decimals: Option<&str>) -> String {
+    if !self.include_numeral { return name; }
+    match decimals {
+      Some(decimals) => format!("{name} ({},{decimals})", format_danish_grouped(integer, self.numeral_grouping)),
+      None => format!("{name} ({})", format_danish_grouped(integer, self.numeral_grouping))
+    }
+  }
+
+  // The word for "hundred". Some texts elide the final "e" - "et hundred og fem"
+  pub fn hundred(mut self, word: &'static str) -> Self {
+    self.hundred = word;
+    self
+  }
+
+  // Only say "og" once, before the final component of the whole number, instead of once per group
+  // 1_105_065 -> "en million et hundrede fem tusind og femogtres"
+  pub fn single_final_og(mut self, enabled: bool) -> Self {
+    self.single_final_og = enabled;
+    self
+  }
+
+  // The word between the integer part and the decimals. Mixed technical text sometimes says "punktum"
+  pub fn decimal_separator(mut self, word: &'static str) -> Self {
+    self.decimal_separator = word;
+    self
+  }
+
+  // Hyphenate compounds of ones and tens, "syv-og-tyve" instead of "syvogtyve"
+  pub fn hyphenate_compounds(mut self, enabled: bool) -> Self {
+    self.hyphenate_compounds = enabled;
+    self
+  }
+
+  // How the decimals are read, see DecimalMode
+  pub fn decimal_mode(mut self, mode: DecimalMode) -> Self {
+    self.decimal_mode = mode;
+    self
+  }
+
+  // Which form of "one" is used where one stands on its own, see OneForm
+  pub fn one_form(mut self, form: OneForm) -> Self {
+    self.one_form = form;
+    self
+  }
+
+  // Whether trailing zeros of decimals given as a string are trimmed, like they are for f64
+  // Untrimmed, "2.50" reads "to komma fem, nul"
+  pub fn trim_trailing_zeros(mut self, enabled: bool) -> Self {
+    self.trim_trailing_zeros = enabled;
+    self
+  }
+
+  // What goes between the thousands' groups. A comma gives a pause when read aloud
+  // ", " -> "syv millioner, treogtyve tusind, fire hundrede og enogtres"
+  pub fn group_separator(mut self, separator: &'static str) -> Self {
+    self.group_separator = separator;
+    self
+  }
+
+  // Round floats to a fixed number of decimal places, and always read that many - even if they are zero
+  // Some(1) -> 0.0 reads "nul komma nul". None reads the decimals the float has, so 0.0 reads "nul"
+  pub fn decimal_places(mut self, places: Option<usize>) -> Self {
+    self.decimal_places = places;
+    self
+  }
+
+  // The word for "thousand", used for every thousands' group. Some prefer the definite "tusinde" - "treogtyve tusinde"
+  pub fn thousand(mut self, word: &'static str) -> Self {
+    self.thousand = word;
+    self
+  }
+
+  // Write the thousands together with the word for thousand, as in written text - "treogtyvetusind" instead of "treogtyve tusind"
+  pub fn compound_thousands(mut self, enabled: bool) -> Self {
+    self.compound_thousands = enabled;
+    self
+  }
+
+  // The letter case of the whole name, see Case
+  pub fn case(mut self, case: Case) -> Self {
+    self.case = case;
+    self
+  }
+
+  // Always stress the one in front of "hundrede" and the orders of magnitude - "ét hundrede", "ét tusind" and "én million"
+  pub fn always_explicit_one(mut self, enabled: bool) -> Self {
+    self.always_explicit_one = enabled;
+    self
+  }
+
+  // A phrase said after the whole name, after any unit word too. Receipts say "i alt" - "et hundrede kroner i alt"
+  pub fn suffix(mut self, phrase: Option<&'static str>) -> Self {
+    self.suffix = phrase;
+    self
+  }
+
+  // Follow the name with the number written in digits in parentheses, as legal and financial documents do
+  // "et hundrede og femogtyve tusind (125.000) kroner"
+  pub fn include_numeral(mut self, enabled: bool) -> Self {
+    self.include_numeral = enabled;
+    self
+  }
+
+  // How the groups of the numeral from include_numeral are separated, see GroupStyle
+  pub fn numeral_grouping(mut self, style: GroupStyle) -> Self {
+    self.numeral_grouping = style;
+    self
+  }
+
+  // Read exactly 50 and 500 by their colloquial names, "et halvt hundrede" and "et halvt tusinde"
+  pub fn half_idioms(mut self, enabled: bool) -> Self {
+    self.half_idioms = enabled;
+    self
+  }
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::DanishCompoundNumeral;
+
+  #[test]
+  fn hundred_word_can_be_elided() {
+    let config = DanishConfig::new().hundred("hundred");
+    assert_eq!(105.danish_compound_numeral_name_with(&config), "et hundred og fem");
+    assert_eq!(200.danish_compound_numeral_name_with(&config), "to hundred");
+    assert_eq!(1_105.danish_compound_numeral_name_with(&config), "et tusind et hundred og fem");
+  }
+
+  #[test]
+  fn hundred_word_defaults_to_hundrede() {
+    let config = DanishConfig::new();
+    assert_eq!(105.danish_compound_numeral_name_with(&config), "et hundrede og fem");
+    assert_eq!(200.danish_compound_numeral_name_with(&config), "to hundrede");
+  }
+
+  #[test]
+  fn single_final_og_keeps_only_the_last_og() {
+    let config = DanishConfig::new().single_final_og(true);
+    assert_eq!(2_101_065.danish_compound_numeral_name_with(&config), "to millioner et hundrede et tusind og femogtres");
+    assert_eq!(2_101_065.danish_compound_numeral_name(), "to millioner et hundrede og et tusind og femogtres");
+    assert_eq!(1_000_065.danish_compound_numeral_name_with(&config), "en million og femogtres");
+  }
+
+  #[test]
+  fn decimal_separator_defaults_to_komma() {
+    assert_eq!(2.5.danish_compound_numeral_name_with(&DanishConfig::new()), "to komma fem");
+    #[allow(deprecated)]
+    let misspelled = crate::DECIMAL_SEPERATOR;
+    assert_eq!(misspelled, DECIMAL_SEPARATOR);
+  }
+
+  #[test]
+  fn decimal_separator_can_be_overridden() {
+    let config = DanishConfig::new().decimal_separator("punktum");
+    assert_eq!(2.5.danish_compound_numeral_name_with(&config), "to punktum fem");
+  }
+
+  #[test]
+  fn hyphenate_compounds_joins_ones_and_tens() {
+    let config = DanishConfig::new().hyphenate_compounds(true);
+    assert_eq!(27.danish_compound_numeral_name_with(&config), "syv-og-tyve");
+    assert_eq!(127.danish_compound_numeral_name_with(&config), "et hundrede og syv-og-tyve");
+    assert_eq!(21_000.danish_compound_numeral_name_with(&config), "en-og-tyve tusind");
+  }
+
+  #[test]
+  fn hyphenate_compounds_leaves_other_numbers_alone() {
+    let config = DanishConfig::new().hyphenate_compounds(true);
+    assert_eq!(7.danish_compound_numeral_name_with(&config), "syv");
+    assert_eq!(20.danish_compound_numeral_name_with(&config), "tyve");
+    assert_eq!(27.danish_compound_numeral_name(), "syvogtyve");
+  }
+
+  #[test]
+  fn one_form_defaults_to_the_emphasised_one() {
+    let config = DanishConfig::new();
+    assert_eq!(1.danish_compound_numeral_name_with(&config), "et");
+    assert_eq!(101.danish_compound_numeral_name_with(&config), "et hundrede og én");
+    assert_eq!(1_000_001.danish_compound_numeral_name_with(&config), "en million og én");
+  }
+
+  #[test]
+  fn one_form_can_turn_the_emphasis_off() {
+    let config = DanishConfig::new().one_form(OneForm::Neuter);
+    assert_eq!(1.danish_compound_numeral_name_with(&config), "et");
+    assert_eq!(101.danish_compound_numeral_name_with(&config), "et hundrede og et");
+    assert_eq!(1_000_001.danish_compound_numeral_name_with(&config), "en million og et");
+
+    let config = DanishConfig::new().one_form(OneForm::Common);
+    assert_eq!(1.danish_compound_numeral_name_with(&config), "en");
+    assert_eq!(101.danish_compound_numeral_name_with(&config), "et hundrede og en");
+    assert_eq!(1_000_001.danish_compound_numeral_name_with(&config), "en million og en");
+  }
+
+  #[test]
+  fn group_separator_goes_between_the_top_level_groups() {
+    let config = DanishConfig::new().group_separator(", ");
+    assert_eq!(7_023_461.danish_compound_numeral_name_with(&config), "syv millioner, treogtyve tusind, fire hundrede og enogtres");
+    assert_eq!((-7_023_461).danish_compound_numeral_name_with(&config), "minus syv millioner, treogtyve tusind, fire hundrede og enogtres");
+    assert_eq!(7_023_461.danish_compound_numeral_name(), "syv millioner treogtyve tusind fire hundrede og enogtres");
+  }
+
+  #[test]
+  fn fixed_decimal_places_keep_zero_decimals() {
+    let config = DanishConfig::new().decimal_places(Some(1));
+    assert_eq!(0.0.danish_compound_numeral_name_with(&config), "nul komma nul");
+    assert_eq!((-0.0).danish_compound_numeral_name_with(&config), "nul komma nul");
+    assert_eq!(2.0.danish_compound_numeral_name_with(&DanishConfig::new().decimal_places(Some(2))), "to komma nul, nul");
+    assert_eq!(0.0.danish_compound_numeral_name(), "nul");
+  }
+
+  #[test]
+  fn thousand_word_is_the_same_in_every_position() {
+    let config = DanishConfig::new().thousand("tusinde");
+    assert_eq!(23_000.danish_compound_numeral_name_with(&config), "treogtyve tusinde");
+    assert_eq!(13_000.danish_compound_numeral_name_with(&config), "tretten tusinde");
+    assert_eq!(30_000.danish_compound_numeral_name_with(&config), "tredive tusinde");
+
+    let config = config.compound_thousands(true);
+    assert_eq!(23_000.danish_compound_numeral_name_with(&config), "treogtyvetusinde");
+    assert_eq!(13_000.danish_compound_numeral_name_with(&config), "trettentusinde");
+    assert_eq!(30_000.danish_compound_numeral_name_with(&config), "tredivetusinde");
+  }
+
+  #[test]
+  fn case_applies_to_the_whole_name() {
+    let name = |case| 1_000_500.danish_compound_numeral_name_with(&DanishConfig::new().case(case));
+    assert_eq!(name(Case::Lower), "en million og fem hundrede");
+    assert_eq!(name(Case::UpperFirst), "En million og fem hundrede");
+    assert_eq!(name(Case::Upper), "EN MILLION OG FEM HUNDREDE");
+    assert_eq!(name(Case::TitleEach), "En Million Og Fem Hundrede");
+  }
+
+  #[test]
+  fn case_handles_danish_letters() {
+    assert_eq!(Case::Upper.apply("første år".to_string()), "FØRSTE ÅR");
+    assert_eq!(Case::TitleEach.apply("ældre øre".to_string()), "Ældre Øre");
+  }
+
+  #[test]
+  fn always_explicit_one_emphasises_one_before_scale_words() {
+    let config = DanishConfig::new().always_explicit_one(true);
+    assert_eq!(100.danish_compound_numeral_name_with(&config), "ét hundrede");
+    assert_eq!(1_000.danish_compound_numeral_name_with(&config), "ét tusind");
+    assert_eq!(1_101.danish_compound_numeral_name_with(&config), "ét tusind ét hundrede og én");
+    assert_eq!(1_000_000.danish_compound_numeral_name_with(&config), "én million");
+    assert_eq!(1_000.danish_compound_numeral_name_with(&config.compound_thousands(true)), "éttusind");
+  }
+
+  #[test]
+  fn suffix_comes_once_after_the_name() {
+    let config = DanishConfig::new().suffix(Some("i alt"));
+    assert_eq!(100.danish_compound_numeral_name_with(&config), "et hundrede i alt");
+    assert_eq!(2.5.danish_compound_numeral_name_with(&config), "to komma fem i alt");
+  }
+
+  #[test]
+  fn suffix_comes_after_the_currency_units() {
+    let config = DanishConfig::new().suffix(Some("i alt"));
+    let name = crate::danish_currency_config(100.5, crate::CurrencySign::Prefix, &config).unwrap();
+    assert_eq!(name, "et hundrede kroner og halvtreds øre i alt");
+    assert_eq!(name.matches("i alt").count(), 1);
+  }
+
+  #[test]
+  fn include_numeral_echoes_the_grouped_digits() {
+    let config = DanishConfig::new().include_numeral(true);
+    assert_eq!(1_234_567.danish_compound_numeral_name_with(&config), "en million to hundrede og fireogtredive tusind fem hundrede og syvogtres (1.234.567)");
+    assert_eq!((-2.5).danish_compound_numeral_name_with(&config), "minus to komma fem (-2,5)");
+    let amount = crate::danish_currency_config(1_234_567.0, crate::CurrencySign::Prefix, &config).unwrap();
+    assert!(amount.ends_with("(1.234.567) kroner"), "{amount}");
+  }
+
+  #[test]
+  fn half_idioms_name_fifty_and_five_hundred() {
+    let config = DanishConfig::new().half_idioms(true);
+    assert_eq!(50.danish_compound_numeral_name_with(&config), "et halvt hundrede");
+    assert_eq!(500.danish_compound_numeral_name_with(&config), "et halvt tusinde");
+    assert_eq!(50.danish_compound_numeral_name(), "halvtreds");
+  }
+
+  #[test]
+  fn half_idioms_are_only_for_the_whole_number() {
+    let config = DanishConfig::new().half_idioms(true);
+    assert_eq!(150.danish_compound_numeral_name_with(&config), "et hundrede og halvtreds");
+    assert_eq!(50_000.danish_compound_numeral_name_with(&config), "halvtreds tusind");
+    assert_eq!(50.5.danish_compound_numeral_name_with(&config), "halvtreds komma fem");
+  }
+}
@@ -0,0 +1,191 @@
+//
+// IMPORTS
+//
+
+use crate::{decimals_name, integer_name, ConvertError, DanishCompoundNumeral, DanishConfig, DanishError, MINUS};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+const INFINITY: &str = "uendelig";
+const NOT_A_NUMBER: &str = "ikke et tal";
+const TOO_LARGE: &str = "et for stort tal"; // A number too large to name exactly
+
+//
+// PARSE AND CONVERT
+//
+
+// Returns the Danish reading of a float that is not finite, "uendelig", "minus uendelig" or "ikke et tal" for NaN
+// Finite floats have a proper name, so they give None
+pub fn danish_non_finite(number: f64) -> Option<String> {
+  if number.is_nan() { return Some(NOT_A_NUMBER.to_string()); }
+  if number.is_infinite() {
+    return Some(if number < 0.0 { format!("{MINUS} {INFINITY}") } else { INFINITY.to_string() });
+  }
+  None
+}
+
+// Returns the Danish reading of a float too large to name, "et for stort tal" or "minus et for stort tal"
+// Used where a name is needed anyway, rather than naming the wrong number the float saturates at
+pub(crate) fn danish_too_large(number: f64) -> String {
+  if number < 0.0 { format!("{MINUS} {TOO_LARGE}") } else { TOO_LARGE.to_string() }
+}
+
+// Parses a string as a number and returns its Danish compound numeral name
+pub fn parse_and_convert(s: &str) -> Result<String, ConvertError> {
+  parse_and_convert_with(s, &DanishConfig::default())
+}
+
+// Same as parse_and_convert, but spelled according to the given config
+// Integers written in hexadecimal, binary or octal like "0x2A", "0b101010" and "0o52" are read as the integer they stand for
+pub fn parse_and_convert_with(s: &str, config: &DanishConfig) -> Result<String, ConvertError> {
+  if let Some(integer) = radix_integer(s.trim()) { return Ok(integer?.danish_compound_numeral_name_with(config)); }
+  let number = s.trim().parse::<f64>().map_err(|_| ConvertError::InvalidNumber(s.trim().to_string()))?;
+
+  number.try_danish_compound_numeral_name_with(config)
+}
+
+// Returns the integer of a string with a radix prefix, "0x2A", "-0b101" or "0o52", or None if it has no prefix
+// The prefix may be either case, and digits that do not belong to the radix are errors
+fn radix_integer(s: &str) -> Option<Result<i128, DanishError>> {
+  let (negative, unsigned) = match s.strip_prefix('-') {
+    Some(unsigned) => (true, unsigned),
+    None => (false, s.strip_prefix('+').unwrap_or(s))
+  };
+  let radix = match unsigned.get(..2).map(str::to_ascii_lowercase).as_deref() {
+    Some("0x") => 16,
+    Some("0b") => 2,
+    Some("0o") => 8,
+    _ => return None
+  };
+
+  let digits = &unsigned[2..];
+  if digits.is_empty() { return Some(Err(DanishError::EmptyInput)); }
+  if let Some(c) = digits.chars().find(|c| !c.is_digit(radix)) { return Some(Err(DanishError::InvalidDigit(c))); }
+
+  let magnitude = digits.chars().try_fold(0_u128, |magnitude, c| magnitude.checked_mul(radix as u128)?.checked_add(c.to_digit(radix)? as u128));
+  let integer = magnitude.and_then(|magnitude| if negative { 0_i128.checked_sub_unsigned(magnitude) } else { i128::try_from(magnitude).ok() });
+  // A number too large is shown in decimal, like the error of the same number written in decimal
+  Some(integer.ok_or_else(|| DanishError::too_large(&format!("{}{}", if negative { "-" } else { "" }, decimal_digits(digits, radix)))))
+}
+
+// Returns the decimal digits of a number written in another radix, however large it is
+// The number is built up in limbs of nine decimal digits each, the least significant first
+fn decimal_digits(digits: &str, radix: u32) -> String {
+  const LIMB: u64 = 1_000_000_000;
+  let mut limbs: Vec<u64> = vec![0];
+  for c in digits.chars() {
+    let mut carry = c.to_digit(radix).unwrap_or(0) as u64;
+    for limb in &mut limbs {
+      let value = *limb * radix as u64 + carry;
+      *limb = value % LIMB;
+      carry = value / LIMB;
+    }
+    if carry > 0 { limbs.push(carry); } // The carry is below the radix, so it fits in a single limb
+  }
+
+  let mut decimal = limbs[limbs.len() - 1].to_string();
+  for limb in limbs.iter().rev().skip(1) { decimal.push_str(&format!("{limb:09}")); }
+  decimal
+}
+
+// Parses an integer, written in decimal or with a radix prefix like parse_and_convert reads it - "42", "0x2A" or "-0b101"
+pub fn parse_integer(s: &str) -> Result<i128, DanishError> {
+  let s = s.trim();
+  if let Some(integer) = radix_integer(s) { return integer; }
+  s.parse::<i128>().map_err(|_| DanishError::InvalidNumber(s.to_string()))
+}
+
+// Returns the Danish compound numeral name of a decimal number given as a string, like "2.50"
+// Unlike going through f64, the digits are read exactly as written. Trailing zeros are trimmed unless the config says otherwise
+pub fn danish_decimal_string(s: &str, config: &DanishConfig) -> Result<String, DanishError> {
+  let s = s.trim();
+  let (negative, unsigned) = match s.strip_prefix('-') {
+    Some(unsigned) => (true, unsigned),
+    None => (false, s.strip_prefix('+').unwrap_or(s))
+  };
+  let (integer, decimals) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+  if integer.is_empty() && decimals.is_empty() { return Err(DanishError::EmptyInput); }
+  if let Some(c) = integer.chars().chain(decimals.chars()).find(|c| !c.is_ascii_digit()) {
+    return Err(DanishError::InvalidDigit(c));
+  }
+
+  let decimals = if config.trim_trailing_zeros { decimals.trim_end_matches('0') } else { decimals };
+  let integer = if integer.is_empty() { 0 } else { integer.parse::<i128>().map_err(|_| DanishError::too_large(&format!("{}{integer}", if negative { "-" } else { "" })))? }; // ".5" is 0.5
+
+  let string = if decimals.is_empty() {
+    integer_name(integer, config)
+  } else {
+    format!("{} {} {}", integer_name(integer, config), config.decimal_separator, decimals_name(decimals, config))
+  };
+
+  // A negative zero like "-0.0" is still zero, and has no sign to read
+  let zero = integer == 0 && decimals.chars().all(|c| c == '0');
+  let (string, sign) = if negative && !zero { (format!("{MINUS} {string}"), "-") } else { (string, "") };
+  let decimals = Some(decimals).filter(|decimals| !decimals.is_empty());
+  Ok(config.finish(config.with_numeral(string, &format!("{sign}{integer}"), decimals)))
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_and_convert_names_numbers() {
+    assert_eq!(parse_and_convert("42").unwrap(), "toogfyrre");
+    assert_eq!(parse_and_convert(" 2.5 ").unwrap(), "to komma fem");
+  }
+
+  #[test]
+  fn parse_and_convert_tells_errors_apart() {
+    assert_eq!(parse_and_convert("abc"), Err(ConvertError::InvalidNumber("abc".to_string())));
+    assert!(matches!(parse_and_convert("1e40"), Err(ConvertError::TooLarge(_))));
+    assert_eq!(parse_and_convert("NaN"), Err(ConvertError::NonFinite));
+    assert_eq!(parse_and_convert("-inf"), Err(ConvertError::NonFinite));
+  }
+
+  #[test]
+  fn decimal_strings_trim_trailing_zeros_by_default() {
+    let config = DanishConfig::new();
+    assert_eq!(danish_decimal_string("2.50", &config).unwrap(), "to komma fem");
+    assert_eq!(danish_decimal_string("2.00", &config).unwrap(), "to");
+  }
+
+  #[test]
+  fn decimal_strings_can_keep_trailing_zeros() {
+    let config = DanishConfig::new().trim_trailing_zeros(false);
+    assert_eq!(danish_decimal_string("2.50", &config).unwrap(), "to komma fem, nul");
+  }
+
+  #[test]
+  fn radix_prefixes_name_the_same_integer() {
+    assert_eq!(parse_and_convert("0x2A").unwrap(), "toogfyrre");
+    assert_eq!(parse_and_convert("0b101010").unwrap(), "toogfyrre");
+    assert_eq!(parse_and_convert("0o52").unwrap(), "toogfyrre");
+    assert_eq!(parse_and_convert("-0X2a").unwrap(), "minus toogfyrre");
+    assert_eq!(parse_integer("-0x80000000000000000000000000000000"), Ok(i128::MIN));
+  }
+
+  #[test]
+  fn radix_digits_must_belong_to_the_radix() {
+    assert_eq!(parse_and_convert("0b102"), Err(ConvertError::InvalidDigit('2')));
+    assert_eq!(parse_and_convert("0x"), Err(ConvertError::EmptyInput));
+    // An invalid digit is reported even past the range of i128
+    assert_eq!(parse_integer("0xfffffffffffffffffffffffffffffffffg"), Err(DanishError::InvalidDigit('g')));
+  }
+
+  #[test]
+  fn radix_numbers_too_large_show_their_decimal_digits() {
+    // 2^128 and -(2^127 + 1), the first numbers past u128 and i128
+    assert_eq!(parse_integer("0x100000000000000000000000000000000"), Err(DanishError::too_large("340282366920938463463374607431768211456")));
+    assert_eq!(parse_integer("-0x80000000000000000000000000000001"), Err(DanishError::too_large("-170141183460469231731687303715884105729")));
+    let decimal = danish_decimal_string("170141183460469231731687303715884105728", &DanishConfig::new());
+    assert_eq!(parse_integer("0x80000000000000000000000000000000"), decimal.map(|_| 0));
+  }
+}
@@ -0,0 +1,493 @@
+//
+// IMPORTS
+//
+
+use crate::{DanishCompoundNumeral, DanishError, DanishIntegerNumeral, Gender, AND, EMPH_NEUTER_ONE, EMPH_ONE, MINUS, NEUTER_ONE, NUMBER_NAMES, PLURAL_SUFFIX};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+const PERCENT: &str = "procent"; // Same in singular and plural
+const BASIS_POINT: &str = "basispunkt";
+const BASIS_POINTS: &str = "basispunkter";
+const DEFINITE_ONE: &str = "ene"; // "den ene bog"
+const PLURAL_ARTICLE: &str = "de";
+const DEGREE: &str = "grad";
+const DEGREES: &str = "grader";
+const GENITIVE: &str = "s";    // "graders frost"
+const FROST: &str = "frost";
+const FEW: &str = "få";
+const MANY: &str = "mange";
+const APPROXIMATELY: &str = "cirka";
+const AROUND: &str = "omkring";
+const JUST_UNDER: &str = "knap";
+const JUST_OVER: &str = "godt";
+
+// Prefixes for fractions of a unit, largest first
+const SI_PREFIXES: [(f64, &str); 4] = [(1e-3, "milli"), (1e-6, "mikro"), (1e-9, "nano"), (1e-12, "piko")];
+
+//
+// MAGNITUDE COUNTS
+//
+
+// Returns a loose count of an order of magnitude followed by a noun, "to millioner mennesker"
+// The scale index is the index of the order of magnitude in NUMBER_NAMES[3] - 0 for "tusind", 1 for "million" and so on
+// Leave the noun empty for just the count, "en million"
+pub fn danish_magnitude_count(count: i128, scale_index: usize, noun: &str) -> Result<String, DanishError> {
+  let scale = NUMBER_NAMES[3].get(scale_index).ok_or(DanishError::OutOfRange)?;
+
+  // Only "thousands" is neuter gender, and it is also the only one without a plural suffix
+  // Minus one is singular too, with its sign in front - "minus en million"
+  let string = if count.unsigned_abs() == 1 {
+    let sign = if count < 0 { format!("{MINUS} ") } else { String::new() };
+    format!("{sign}{} {scale}", if scale_index == 0 { NEUTER_ONE } else { NUMBER_NAMES[0][1] })
+  } else {
+    format!("{} {scale}{}", count.danish_compound_numeral_name(), if scale_index > 0 { PLURAL_SUFFIX } else { "" })
+  };
+
+  Ok(if noun.is_empty() { string } else { format!("{string} {noun}") })
+}
+
+//
+// UNITS
+//
+
+// A counted noun, with the gender the number one agrees with
+// DanishUnit::new("krone", "kroner", Gender::Common) and DanishUnit::new("år", "år", Gender::Neuter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DanishUnit {
+  singular: &'static str,
+  plural: &'static str,
+  gender: Gender
+}
+
+impl DanishUnit {
+  pub const fn new(singular: &'static str, plural: &'static str, gender: Gender) -> Self {
+    DanishUnit { singular, plural, gender }
+  }
+}
+
+// Returns the Danish reading of a count of a unit, with one in the gender of the unit - "én krone", "ét år" and "to år"
+// A final one agrees with the unit too, 101 years -> "et hundrede og ét år"
+pub fn danish_with_unit(n: i128, unit: &DanishUnit) -> String {
+  let one = match unit.gender { Gender::Common => EMPH_ONE, Gender::Neuter => EMPH_NEUTER_ONE };
+  let magnitude = n.magnitude_spelled();
+  let name = match magnitude.strip_suffix(EMPH_ONE) {
+    _ if n.unsigned_abs() == 1 => format!("{one} {}", unit.singular),
+    Some(rest) if n.uses_emphasised_one() => format!("{rest}{one} {}", unit.plural),
+    _ => format!("{magnitude} {}", unit.plural)
+  };
+
+  if n < 0 { format!("{MINUS} {name}") } else { name }
+}
+
+// Returns the definite reading of a count of a unit, with the article in front - "de to bøger"
+// One takes the article of the gender of the unit and the definite "ene", "den ene bog" and "det ene år"
+// Only counts of one and up have a definite reading, as "de nul bøger" is not said
+pub fn danish_definite_noun(n: i128, unit: &DanishUnit) -> Result<String, DanishError> {
+  if n < 1 { return Err(DanishError::OutOfRange); }
+  if n == 1 { return Ok(format!("{} {DEFINITE_ONE} {}", unit.gender.article(), unit.singular)); }
+  Ok(format!("{PLURAL_ARTICLE} {} {}", n.danish_compound_numeral_name(), unit.plural))
+}
+
+// Returns the Danish name of a number with a unit after each of its thousands' groups, as some legal templates have it
+// Each group is counted like danish_with_unit, and like a list the last two are joined by "og" and the rest by commas
+// 2_023_000 kroner -> "to millioner kroner og treogtyve tusind kroner", and 1_000_001 -> "en million kroner og én krone"
+pub fn danish_repeat_noun(n: i128, unit: &DanishUnit) -> String {
+  // The value of each nonzero group, least significant first - 2_023_000 -> [23_000, 2_000_000]
+  // A group is worth less than the magnitude unless it is the only one, so even the groups of i128::MIN fit in i128
+  let mut values = Vec::new();
+  let (mut rest, mut scale) = (n.unsigned_abs(), 1_u128);
+  while rest > 0 {
+    if rest % 1000 > 0 { values.push(((rest % 1000) * scale) as i128); }
+    rest /= 1000;
+    scale = scale.saturating_mul(1000);
+  }
+
+  let groups: Vec<String> = values.into_iter().rev().map(|value| danish_with_unit(value, unit)).collect();
+  let name = match groups.split_last() {
+    None => return danish_with_unit(0, unit),
+    Some((last, rest)) if !rest.is_empty() => format!("{} {AND} {last}", rest.join(", ")),
+    Some((last, _)) => last.clone()
+  };
+  if n < 0 { format!("{MINUS} {name}") } else { name }
+}
+
+//
+// APPROXIMATIONS
+//
+
+// The word in front of a rounded figure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Qualifier {
+  #[default]
+  Approximately, // "cirka tusind"
+  Around,        // "omkring tusind"
+  JustUnder,     // The figure was rounded up, "knap tusind"
+  JustOver       // The figure was rounded down, "godt tusind"
+}
+
+impl Qualifier {
+  fn word(self) -> &'static str {
+    match self {
+      Qualifier::Approximately => APPROXIMATELY,
+      Qualifier::Around => AROUND,
+      Qualifier::JustUnder => JUST_UNDER,
+      Qualifier::JustOver => JUST_OVER
+    }
+  }
+}
+
+// Returns the Danish reading of a number rounded to the nearest multiple of a step, with a qualifier in front
+// Without a qualifier of its own the direction of the rounding picks one - 980 to the nearest 100 -> "knap et tusind"
+// and 1030 -> "godt et tusind". A number that needs no rounding is read as it is
+pub fn danish_approximate(number: f64, step: f64, qualifier: Option<Qualifier>) -> Result<String, DanishError> {
+  if !step.is_finite() || step <= 0.0 { return Err(DanishError::OutOfRange); }
+  // The rounded figure is rounded again to get rid of float noise like 3 * 0.1 = 0.30000000000000004
+  let rounded = ((number / step).round() * step * 1e9).round() / 1e9;
+  let name = rounded.try_danish_compound_numeral_name()?;
+
+  let qualifier = match qualifier {
+    Some(qualifier) => qualifier,
+    None if rounded > number => Qualifier::JustUnder,
+    None if rounded < number => Qualifier::JustOver,
+    None => return Ok(name)
+  };
+  Ok(format!("{} {name}", qualifier.word()))
+}
+
+//
+// VAGUE COUNTS
+//
+
+// Options for reading counts vaguely. By default 1 to 3 are "få" and 100 and up are "mange"
+// Built like VagueConfig::new().many_from(20).many_word("flere")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VagueConfig {
+  pub(crate) few_up_to: i128,
+  pub(crate) many_from: i128,
+  pub(crate) few_word: &'static str,
+  pub(crate) many_word: &'static str
+}
+
+impl Default for VagueConfig {
+  fn default() -> Self {
+    VagueConfig { few_up_to: 3, many_from: 100, few_word: FEW, many_word: MANY }
+  }
+}
+
+impl VagueConfig {
+  pub fn new() -> Self { Self::default() }
+
+  // The largest count that is read as few. Counts from 1 up to it are few
+  pub fn few_up_to(mut self, threshold: i128) -> Self {
+    self.few_up_to = threshold;
+    self
+  }
+
+  // The smallest count that is read as many
+  pub fn many_from(mut self, threshold: i128) -> Self {
+    self.many_from = threshold;
+    self
+  }
+
+  // The word for a few, "få" by default
+  pub fn few_word(mut self, word: &'static str) -> Self {
+    self.few_word = word;
+    self
+  }
+
+  // The word for many, "mange" by default. "flere" is a milder choice
+  pub fn many_word(mut self, word: &'static str) -> Self {
+    self.many_word = word;
+    self
+  }
+}
+
+// Returns a vague reading of a count, the word for a few or many when the count is past the thresholds of the config
+// Counts between the thresholds are read exactly - 2 -> "få", 42 -> "toogfyrre" and 500 -> "mange"
+// Zero and negative counts are never few, as "få" means there are some
+pub fn danish_vague_count(n: i128, config: &VagueConfig) -> String {
+  if n >= config.many_from { return config.many_word.to_string(); }
+  if (1..=config.few_up_to).contains(&n) { return config.few_word.to_string(); }
+  n.danish_compound_numeral_name()
+}
+
+//
+// PERCENTAGES
+//
+
+// The unit a percentage is read in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unit {
+  #[default]
+  Percent,    // 2.5 -> "to komma fem procent"
+  BasisPoints // Hundredths of a percent, 0.25 -> "femogtyve basispunkter"
+}
+
+// Returns the Danish reading of a percentage, read in the given unit
+// Percentages that are not finite or too large to name are errors
+pub fn danish_percent(percent: f64, unit: Unit) -> Result<String, DanishError> {
+  // Basis points are rounded to get rid of float noise like 0.07 * 100 = 7.000000000000001
+  let value = match unit {
+    Unit::Percent => percent,
+    Unit::BasisPoints => (percent * 100.0 * 1e9).round() / 1e9
+  };
+
+  // "procent" is common gender, while "basispunkt" is neuter like the plain name of one
+  let name = match (unit, value.abs() == 1.0) {
+    (Unit::Percent, true) => format!("{} {PERCENT}", NUMBER_NAMES[0][1]),
+    (Unit::Percent, false) => format!("{} {PERCENT}", value.abs().try_danish_compound_numeral_name()?),
+    (Unit::BasisPoints, true) => format!("{NEUTER_ONE} {BASIS_POINT}"),
+    (Unit::BasisPoints, false) => format!("{} {BASIS_POINTS}", value.abs().try_danish_compound_numeral_name()?)
+  };
+
+  Ok(if value < 0.0 { format!("{MINUS} {name}") } else { name })
+}
+
+//
+// TEMPERATURES
+//
+
+// How a temperature below zero is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TemperatureStyle {
+  #[default]
+  Minus, // "minus fem grader"
+  Frost  // As weather reports say it, "fem graders frost"
+}
+
+// Returns the Danish reading of a temperature in degrees, 21 -> "enogtyve grader"
+// "grad" is common gender, so one degree is "en grad". Temperatures above zero read the same in both styles
+// Temperatures that are not finite or too large to name are errors, like the amounts of danish_currency
+pub fn danish_temperature(degrees: f64, style: TemperatureStyle) -> Result<String, DanishError> {
+  let (count, unit) = if degrees.abs() == 1.0 {
+    (NUMBER_NAMES[0][1].to_string(), DEGREE)
+  } else {
+    (degrees.abs().try_danish_compound_numeral_name()?, DEGREES)
+  };
+
+  Ok(match style {
+    _ if degrees >= 0.0 => format!("{count} {unit}"),
+    TemperatureStyle::Minus => format!("{MINUS} {count} {unit}"),
+    TemperatureStyle::Frost => format!("{count} {unit}{GENITIVE} {FROST}")
+  })
+}
+
+//
+// SI PREFIXES
+//
+
+// Returns the Danish reading of a small measurement using the nearest SI prefix
+// 0.001 of DanishUnit::new("meter", "meter", Gender::Common) -> "en millimeter", and 3e-9 sekunder -> "tre nanosekunder"
+// Values that are zero, at least one unit or smaller than the smallest prefix are read as they are with the bare unit
+// Values that are not finite or too large to name are errors
+pub fn danish_si(value: f64, unit: &DanishUnit) -> Result<String, DanishError> {
+  let prefix = SI_PREFIXES.iter().find(|(factor, _)| value.abs() >= *factor && value.abs() < 1.0);
+
+  // The coefficient is rounded to get rid of float noise like 0.007 / 0.001 = 7.000000000000001
+  let (coefficient, prefix) = match prefix {
+    Some((factor, prefix)) => ((value.abs() / factor * 1e9).round() / 1e9, *prefix),
+    None => (value.abs(), "")
+  };
+
+  // A prefix does not change the gender of the unit, so it is "et milligram" but "en millimeter"
+  let name = if coefficient == 1.0 {
+    let one = match unit.gender { Gender::Common => NUMBER_NAMES[0][1], Gender::Neuter => NEUTER_ONE };
+    format!("{one} {prefix}{}", unit.singular)
+  } else {
+    format!("{} {prefix}{}", coefficient.try_danish_compound_numeral_name()?, unit.plural)
+  };
+
+  Ok(if value < 0.0 { format!("{MINUS} {name}") } else { name })
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn magnitude_counts_agree_with_their_scale() {
+    assert_eq!(danish_magnitude_count(1, 1, "").unwrap(), "en million");
+    assert_eq!(danish_magnitude_count(2, 1, "mennesker").unwrap(), "to millioner mennesker");
+    assert_eq!(danish_magnitude_count(1, 2, "").unwrap(), "en milliard");
+    assert_eq!(danish_magnitude_count(3, 2, "kroner").unwrap(), "tre milliarder kroner");
+    assert_eq!(danish_magnitude_count(1, 0, "").unwrap(), "et tusind");
+    assert_eq!(danish_magnitude_count(5, 0, "").unwrap(), "fem tusind");
+  }
+
+  #[test]
+  fn negative_magnitude_counts_keep_their_sign_in_front() {
+    assert_eq!(danish_magnitude_count(-1, 1, "").unwrap(), "minus en million");
+    assert_eq!(danish_magnitude_count(-2, 1, "").unwrap(), "minus to millioner");
+  }
+
+  #[test]
+  fn unknown_scales_are_out_of_range() {
+    assert_eq!(danish_magnitude_count(1, 100, ""), Err(DanishError::OutOfRange));
+  }
+
+  #[test]
+  fn percentages_read_decimals_and_signs() {
+    assert_eq!(danish_percent(2.5, Unit::Percent).unwrap(), "to komma fem procent");
+    assert_eq!(danish_percent(-2.5, Unit::Percent).unwrap(), "minus to komma fem procent");
+    assert_eq!(danish_percent(1.0, Unit::Percent).unwrap(), "en procent");
+  }
+
+  #[test]
+  fn basis_points_are_hundredths_of_a_percent() {
+    assert_eq!(danish_percent(0.25, Unit::BasisPoints).unwrap(), "femogtyve basispunkter");
+    assert_eq!(danish_percent(0.07, Unit::BasisPoints).unwrap(), "syv basispunkter");
+    assert_eq!(danish_percent(0.01, Unit::BasisPoints).unwrap(), "et basispunkt");
+    assert_eq!(danish_percent(-0.25, Unit::BasisPoints).unwrap(), "minus femogtyve basispunkter");
+  }
+
+  #[test]
+  fn percentages_without_a_name_are_errors() {
+    assert_eq!(danish_percent(f64::NAN, Unit::Percent), Err(DanishError::NonFinite));
+    assert_eq!(danish_percent(f64::INFINITY, Unit::BasisPoints), Err(DanishError::NonFinite));
+    assert!(matches!(danish_percent(1e40, Unit::Percent), Err(DanishError::TooLarge(_))));
+  }
+
+  const METER: DanishUnit = DanishUnit::new("meter", "meter", Gender::Common);
+  const SECOND: DanishUnit = DanishUnit::new("sekund", "sekunder", Gender::Neuter);
+  const GRAM: DanishUnit = DanishUnit::new("gram", "gram", Gender::Neuter);
+
+  #[test]
+  fn si_prefixes_name_the_coefficient() {
+    assert_eq!(danish_si(0.001, &METER).unwrap(), "en millimeter");
+    assert_eq!(danish_si(0.007, &METER).unwrap(), "syv millimeter");
+    assert_eq!(danish_si(2e-6, &GRAM).unwrap(), "to mikrogram");
+    assert_eq!(danish_si(1e-6, &GRAM).unwrap(), "et mikrogram");
+  }
+
+  #[test]
+  fn si_units_take_their_plural() {
+    assert_eq!(danish_si(3e-9, &SECOND).unwrap(), "tre nanosekunder");
+    assert_eq!(danish_si(1e-9, &SECOND).unwrap(), "et nanosekund");
+    assert_eq!(danish_si(-0.005, &SECOND).unwrap(), "minus fem millisekunder");
+  }
+
+  #[test]
+  fn si_values_outside_the_prefixes_use_the_bare_unit() {
+    assert_eq!(danish_si(2.0, &SECOND).unwrap(), "to sekunder");
+    assert_eq!(danish_si(0.0, &METER).unwrap(), "nul meter");
+  }
+
+  #[test]
+  fn si_values_without_a_name_are_errors() {
+    assert_eq!(danish_si(f64::NAN, &METER), Err(DanishError::NonFinite));
+    assert_eq!(danish_si(f64::INFINITY, &METER), Err(DanishError::NonFinite));
+    assert!(matches!(danish_si(-1e40, &METER), Err(DanishError::TooLarge(_))));
+  }
+
+  const KRONE: DanishUnit = DanishUnit::new("krone", "kroner", Gender::Common);
+  const YEAR: DanishUnit = DanishUnit::new("år", "år", Gender::Neuter);
+
+  #[test]
+  fn one_agrees_with_the_gender_of_the_unit() {
+    assert_eq!(danish_with_unit(1, &KRONE), "én krone");
+    assert_eq!(danish_with_unit(1, &YEAR), "ét år");
+    assert_eq!(danish_with_unit(-1, &YEAR), "minus ét år");
+    assert_eq!(danish_with_unit(101, &YEAR), "et hundrede og ét år");
+  }
+
+  #[test]
+  fn other_counts_take_the_plural() {
+    assert_eq!(danish_with_unit(5, &KRONE), "fem kroner");
+    assert_eq!(danish_with_unit(21, &YEAR), "enogtyve år");
+  }
+
+  #[test]
+  fn approximations_pick_the_qualifier_from_the_rounding() {
+    assert_eq!(danish_approximate(980.0, 100.0, None).unwrap(), "knap et tusind");
+    assert_eq!(danish_approximate(1030.0, 100.0, None).unwrap(), "godt et tusind");
+    assert_eq!(danish_approximate(1000.0, 100.0, None).unwrap(), "et tusind");
+  }
+
+  #[test]
+  fn approximations_take_a_qualifier_of_their_own() {
+    assert_eq!(danish_approximate(1030.0, 100.0, Some(Qualifier::Around)).unwrap(), "omkring et tusind");
+    assert_eq!(danish_approximate(1000.0, 100.0, Some(Qualifier::Approximately)).unwrap(), "cirka et tusind");
+    assert_eq!(danish_approximate(1030.0, 0.0, None), Err(DanishError::OutOfRange));
+  }
+
+  #[test]
+  fn frost_reads_temperatures_below_zero_as_weather_reports_do() {
+    assert_eq!(danish_temperature(-5.0, TemperatureStyle::Minus).unwrap(), "minus fem grader");
+    assert_eq!(danish_temperature(-5.0, TemperatureStyle::Frost).unwrap(), "fem graders frost");
+    assert_eq!(danish_temperature(-1.0, TemperatureStyle::Frost).unwrap(), "en grads frost");
+  }
+
+  #[test]
+  fn temperatures_above_zero_read_the_same_in_both_styles() {
+    assert_eq!(danish_temperature(5.0, TemperatureStyle::Frost).unwrap(), "fem grader");
+    assert_eq!(danish_temperature(5.0, TemperatureStyle::Minus).unwrap(), "fem grader");
+  }
+
+  #[test]
+  fn temperatures_without_a_name_are_errors() {
+    assert_eq!(danish_temperature(f64::NAN, TemperatureStyle::Minus), Err(DanishError::NonFinite));
+    assert_eq!(danish_temperature(f64::NEG_INFINITY, TemperatureStyle::Frost), Err(DanishError::NonFinite));
+    assert!(matches!(danish_temperature(1e40, TemperatureStyle::Minus), Err(DanishError::TooLarge(_))));
+  }
+
+  const BOOK: DanishUnit = DanishUnit::new("bog", "bøger", Gender::Common);
+
+  #[test]
+  fn definite_nouns_take_the_article_of_their_count() {
+    assert_eq!(danish_definite_noun(1, &BOOK).unwrap(), "den ene bog");
+    assert_eq!(danish_definite_noun(1, &YEAR).unwrap(), "det ene år");
+    assert_eq!(danish_definite_noun(2, &BOOK).unwrap(), "de to bøger");
+  }
+
+  #[test]
+  fn definite_nouns_need_a_count_of_one_or_more() {
+    assert_eq!(danish_definite_noun(0, &BOOK), Err(DanishError::OutOfRange));
+    assert_eq!(danish_definite_noun(-1, &BOOK), Err(DanishError::OutOfRange));
+  }
+
+  #[test]
+  fn vague_counts_are_exact_between_the_thresholds() {
+    let config = VagueConfig::new();
+    assert_eq!(danish_vague_count(2, &config), "få");
+    assert_eq!(danish_vague_count(4, &config), "fire");
+    assert_eq!(danish_vague_count(99, &config), "nioghalvfems");
+    assert_eq!(danish_vague_count(100, &config), "mange");
+  }
+
+  #[test]
+  fn zero_and_negative_counts_are_never_few() {
+    let config = VagueConfig::new();
+    assert_eq!(danish_vague_count(0, &config), "nul");
+    assert_eq!(danish_vague_count(-2, &config), "minus to");
+  }
+
+  #[test]
+  fn vague_counts_take_thresholds_and_words_of_their_own() {
+    let config = VagueConfig::new().few_up_to(5).many_from(20).few_word("et par").many_word("flere");
+    assert_eq!(danish_vague_count(5, &config), "et par");
+    assert_eq!(danish_vague_count(6, &config), "seks");
+    assert_eq!(danish_vague_count(20, &config), "flere");
+  }
+
+  #[test]
+  fn repeat_noun_puts_the_unit_after_each_group() {
+    assert_eq!(danish_repeat_noun(2_023_000, &KRONE), "to millioner kroner og treogtyve tusind kroner");
+    assert_eq!(danish_repeat_noun(5_001_021, &KRONE), "fem millioner kroner, et tusind kroner og enogtyve kroner");
+    assert_eq!(danish_repeat_noun(-2_023_000, &KRONE), "minus to millioner kroner og treogtyve tusind kroner");
+  }
+
+  #[test]
+  fn repeat_noun_counts_each_group_with_the_unit() {
+    assert_eq!(danish_repeat_noun(1_000_001, &KRONE), "en million kroner og én krone");
+    assert_eq!(danish_repeat_noun(1_000_001, &YEAR), "en million år og ét år");
+    assert_eq!(danish_repeat_noun(0, &KRONE), "nul kroner");
+    assert!(danish_repeat_noun(i128::MIN, &KRONE).ends_with("og syv hundrede og otteogtyve kroner"));
+  }
+}
@@ -0,0 +1,124 @@
+//
+// IMPORTS
+//
+
+use crate::{integer_name, DanishConfig, DanishError, AND, FLOAT_INTEGER_LIMIT, MINUS, NUMBER_NAMES};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+const KRONE: &str = "krone";
+const KRONER: &str = "kroner";
+const ORE: &str = "øre"; // Same in singular and plural
+const IN_MINUS: &str = "i minus";
+const NEGATIVE: &str = "negativt";
+
+//
+// CURRENCY
+//
+
+// How a negative amount is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurrencySign {
+  #[default]
+  Prefix,    // "minus fem kroner"
+  InMinus,   // As bank statements say it, "fem kroner i minus"
+  Accounting // The spoken cue for an amount in parentheses in accounts, "fem kroner negativt"
+}
+
+// Returns the Danish name of an amount of Danish kroner, rounded to whole øre
+// 150.5 -> "et hundrede og halvtreds kroner og halvtreds øre"
+// The sign belongs to the whole amount, so it is only said once - "minus en krone og fem øre" and "minus halvtreds øre"
+// Amounts that are not finite, or with more øre than can be named, are errors
+pub fn danish_currency(amount: f64) -> Result<String, DanishError> {
+  danish_currency_with(amount, CurrencySign::Prefix)
+}
+
+// Same as danish_currency, but with a negative amount read in the given way
+pub fn danish_currency_with(amount: f64, sign: CurrencySign) -> Result<String, DanishError> {
+  danish_currency_config(amount, sign, &DanishConfig::default())
+}
+
+// Same as danish_currency_with, but with the amounts spelled by the config
+// The case and suffix of the config go on the whole amount, so a suffix like "i alt" comes after "kroner" and "øre"
+pub fn danish_currency_config(amount: f64, sign: CurrencySign, config: &DanishConfig) -> Result<String, DanishError> {
+  if !amount.is_finite() { return Err(DanishError::NonFinite); }
+  if (amount * 100.0).round().abs() >= FLOAT_INTEGER_LIMIT { return Err(DanishError::too_large(&format!("{:.0}", amount.trunc()))); }
+
+  let ore = (amount * 100.0).round() as i128;
+  let kroner = ore.abs() / 100;
+  let rest = ore.abs() % 100;
+
+  // "krone" is common gender, so one is "en krone" rather than the neuter "et krone"
+  let kroner_string = if kroner == 1 {
+    format!("{} {KRONE}", config.with_numeral(NUMBER_NAMES[0][1].to_string(), "1", None))
+  } else {
+    format!("{} {KRONER}", config.with_numeral(integer_name(kroner, config), &kroner.to_string(), None))
+  };
+
+  // "øre" is neuter gender, which is what the plain name of one already is
+  // Without any whole kroner only the øre are read, 0.5 -> "halvtreds øre"
+  let string = match rest {
+    0 => kroner_string,
+    _ if kroner == 0 => format!("{} {ORE}", config.with_numeral(integer_name(rest, config), &rest.to_string(), None)),
+    _ => format!("{kroner_string} {AND} {} {ORE}", config.with_numeral(integer_name(rest, config), &rest.to_string(), None))
+  };
+
+  Ok(config.finish(match sign {
+    _ if ore >= 0 => string,
+    CurrencySign::Prefix => format!("{MINUS} {string}"),
+    CurrencySign::InMinus => format!("{string} {IN_MINUS}"),
+    CurrencySign::Accounting => format!("{string} {NEGATIVE}")
+  }))
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn amounts_read_kroner_and_ore() {
+    assert_eq!(danish_currency(150.5).unwrap(), "et hundrede og halvtreds kroner og halvtreds øre");
+    assert_eq!(danish_currency(1.0).unwrap(), "en krone");
+    assert_eq!(danish_currency(0.5).unwrap(), "halvtreds øre");
+  }
+
+  #[test]
+  fn negative_amounts_say_the_sign_once() {
+    assert_eq!(danish_currency(-150.5).unwrap(), "minus et hundrede og halvtreds kroner og halvtreds øre");
+    assert_eq!(danish_currency(-0.05).unwrap(), "minus fem øre");
+  }
+
+  #[test]
+  fn amounts_that_cannot_be_named_are_errors() {
+    assert_eq!(danish_currency(f64::NAN), Err(DanishError::NonFinite));
+    assert_eq!(danish_currency(f64::NEG_INFINITY), Err(DanishError::NonFinite));
+    assert!(matches!(danish_currency(1e38), Err(DanishError::TooLarge(_))));
+  }
+
+  #[test]
+  fn negative_amounts_can_be_read_in_minus() {
+    assert_eq!(danish_currency_with(-5.25, CurrencySign::Prefix).unwrap(), "minus fem kroner og femogtyve øre");
+    assert_eq!(danish_currency_with(-5.25, CurrencySign::InMinus).unwrap(), "fem kroner og femogtyve øre i minus");
+    assert_eq!(danish_currency_with(5.25, CurrencySign::InMinus).unwrap(), "fem kroner og femogtyve øre");
+  }
+
+  #[test]
+  fn accounting_reads_negative_amounts_with_a_cue() {
+    assert_eq!(danish_currency_with(-100.0, CurrencySign::Accounting).unwrap(), "et hundrede kroner negativt");
+    assert_eq!(danish_currency_with(100.0, CurrencySign::Accounting).unwrap(), "et hundrede kroner");
+  }
+
+  #[test]
+  fn zero_kroner_are_left_out() {
+    assert_eq!(danish_currency(-0.5).unwrap(), "minus halvtreds øre");
+    assert_eq!(danish_currency(0.5).unwrap(), "halvtreds øre");
+    assert_eq!(danish_currency(0.0).unwrap(), "nul kroner");
+    assert_eq!(danish_currency(-0.0).unwrap(), "nul kroner");
+  }
+}
@@ -0,0 +1,242 @@
+//
+// IMPORTS
+//
+
+use crate::{DanishCompoundNumeral, DanishError, Sign, MINUS, NUMBER_NAMES};
+
+//
+// DIGIT SEQUENCES
+//
+
+const GROUP_PAUSE: &str = ", "; // Spoken pause between groups of digits
+const CHECK_DIGIT: &str = "kontrolciffer";
+const DOUBLE: &str = "dobbelt";
+const TRIPLE: &str = "tredobbelt";
+
+// Returns the raw Danish name of a single digit, 0 -> Some("nul") and 9 -> Some("ni"). Anything above 9 is not a digit
+// The raw names are the counting forms, zero included and with no care for gender
+pub fn digit_word(digit: u8) -> Option<&'static str> {
+  NUMBER_NAMES[0].get(digit as usize).copied()
+}
+
+// Returns the Danish names of each digit in a string of digits, one after another
+// Useful for codes, where "007" should be read "nul nul syv" and not "syv"
+pub fn danish_digit_sequence(digits: &str) -> Result<String, DanishError> {
+  if digits.is_empty() { return Err(DanishError::EmptyInput); }
+
+  // We explicitly want the *raw* digit names - zero included and no care for gender
+  let names = digits.chars()
+    .map(|c| c.to_digit(10).and_then(|d| digit_word(d as u8)).ok_or(DanishError::InvalidDigit(c)))
+    .collect::<Result<Vec<&str>, DanishError>>()?;
+
+  Ok(names.join(" "))
+}
+
+// Returns the Danish names of each digit like danish_digit_sequence, but with runs of two or three identical digits collapsed
+// As is common for phone numbers - "5554" -> "tredobbelt fem fire" and "1224" -> "en dobbelt to fire"
+// Longer runs are read digit by digit, as there is no established word for them
+pub fn danish_digit_sequence_runs(digits: &str) -> Result<String, DanishError> {
+  let names = danish_digit_sequence(digits)?;
+  let names: Vec<&str> = names.split(' ').collect();
+
+  let mut words = vec![];
+  for run in names.chunk_by(|a, b| a == b) {
+    match run.len() {
+      2 => words.push(format!("{DOUBLE} {}", run[0])),
+      3 => words.push(format!("{TRIPLE} {}", run[0])),
+      _ => words.extend(run.iter().map(|name| name.to_string()))
+    }
+  }
+
+  Ok(words.join(" "))
+}
+
+// Returns the Danish names of each digit like danish_digit_sequence, optionally flagging the last digit as the check digit
+// Useful for reading back card and account numbers - "4567" -> "fire fem seks, kontrolciffer syv"
+pub fn danish_digit_sequence_checked(digits: &str, emphasise_check_digit: bool) -> Result<String, DanishError> {
+  let names = danish_digit_sequence(digits)?;
+  if !emphasise_check_digit { return Ok(names); }
+
+  Ok(match names.rsplit_once(' ') {
+    Some((rest, check)) => format!("{rest}{GROUP_PAUSE}{CHECK_DIGIT} {check}"),
+    None => format!("{CHECK_DIGIT} {names}")
+  })
+}
+
+// Returns the Danish reading of a string of digits in groups of three from the right, as some account numbers are read
+// Each group is read as a number, with a pause between groups - "123305007" -> "et hundrede og treogtyve, tre hundrede og fem, nul nul syv"
+// The leading group may be shorter, and leading zeros in a group are read like danish_zero_padded
+pub fn danish_triplets(digits: &str) -> Result<String, DanishError> {
+  if digits.is_empty() { return Err(DanishError::EmptyInput); }
+  if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) { return Err(DanishError::InvalidDigit(c)); }
+
+  // The digits are ASCII, so every byte is a digit and slicing by bytes is safe
+  let first = match digits.len() % 3 { 0 => 3, rest => rest };
+  let groups = std::iter::once(&digits[..first])
+    .chain((first..digits.len()).step_by(3).map(|i| &digits[i..i + 3]));
+
+  let names = groups
+    .map(danish_zero_padded)
+    .collect::<Result<Vec<String>, DanishError>>()?;
+
+  Ok(names.join(GROUP_PAUSE))
+}
+
+// Returns the Danish reading of an ISBN-13/EAN-13 code, group by group with a pause between groups
+// Each group is read as a digit sequence, as leading zeros are significant in publisher and title elements
+// Hyphenated codes like "978-87-7245-510-5" are read in the groups given by the hyphens.
+// Codes without hyphens have no group boundaries for the registration group, publisher and title, as those vary in length.
+// They are read as the three fixed parts every ISBN-13 has - the 3-digit prefix, the 9-digit body and the check digit
+pub fn danish_isbn(code: &str) -> Result<String, DanishError> {
+  let digits: String = code.chars().filter(|c| *c != '-').collect();
+
+  // Validate the digits themselves before we care about the length
+  if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) { return Err(DanishError::InvalidDigit(c)); }
+  if digits.len() != 13 { return Err(DanishError::InvalidLength { expected: 13, found: digits.len() }); }
+
+  // The digits are weighted alternately by 1 and 3, and the weighted sum must be a multiple of 10
+  let sum: u32 = digits.bytes()
+    .enumerate()
+    .map(|(i, b)| (b - b'0') as u32 * if i % 2 == 0 { 1 } else { 3 })
+    .sum();
+  if !sum.is_multiple_of(10) { return Err(DanishError::InvalidCheckDigit); }
+
+  let groups: Vec<&str> = if code.contains('-') {
+    code.split('-').collect()
+  } else {
+    vec![&digits[..3], &digits[3..12], &digits[12..]]
+  };
+
+  // An empty group means a stray hyphen, like "978--87..."
+  if groups.iter().any(|group| group.is_empty()) { return Err(DanishError::InvalidDigit('-')); }
+
+  let names = groups.iter()
+    .map(|group| danish_digit_sequence(group))
+    .collect::<Result<Vec<String>, DanishError>>()?;
+
+  Ok(names.join(GROUP_PAUSE))
+}
+
+// Returns the Danish name of an integer string, keeping any leading zeros
+// Strings with leading zeros are read as digit sequences - "007" -> "nul nul syv" and "-007" -> "minus nul nul syv"
+// Everything else is read as a number - "0" -> "nul" and "42" -> "toogfyrre"
+pub fn danish_zero_padded(s: &str) -> Result<String, DanishError> {
+  let (negative, digits) = match s.strip_prefix('-') {
+    Some(digits) => (true, digits),
+    None => (false, s.strip_prefix('+').unwrap_or(s))
+  };
+
+  let string = if digits.len() > 1 && digits.starts_with('0') {
+    danish_digit_sequence(digits)?
+  } else {
+    if digits.is_empty() { return Err(DanishError::EmptyInput); }
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) { return Err(DanishError::InvalidDigit(c)); }
+    digits.parse::<i128>().map_err(|_| DanishError::too_large(digits))?.danish_compound_numeral_name()
+  };
+
+  Ok(if negative { format!("{MINUS} {string}") } else { string })
+}
+
+// Returns the Danish name of a number padded to a minimum width with leading zeros, each read as "nul"
+// Unlike a digit sequence the number itself is named - 42 at width 3 -> "nul toogfyrre", 5 at width 3 -> "nul nul fem"
+pub fn danish_padded(number: i128, width: usize) -> String {
+  let padding = width.saturating_sub(number.unsigned_abs().to_string().len());
+  let (sign, name) = number.danish_signed();
+
+  let mut words = vec![NUMBER_NAMES[0][0]; padding];
+  words.push(&name);
+  let string = words.join(" ");
+  if sign == Sign::Negative { format!("{MINUS} {string}") } else { string }
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn digit_sequence_reads_every_digit() {
+    assert_eq!(danish_digit_sequence("007").unwrap(), "nul nul syv");
+    assert_eq!(danish_digit_sequence("1234567890").unwrap(), "en to tre fire fem seks syv otte ni nul");
+    assert_eq!(danish_digit_sequence(""), Err(DanishError::EmptyInput));
+    assert_eq!(danish_digit_sequence("12a"), Err(DanishError::InvalidDigit('a')));
+  }
+
+  #[test]
+  fn isbn_is_read_in_its_hyphen_groups() {
+    assert_eq!(
+      danish_isbn("978-87-7245-510-5").unwrap(),
+      "ni syv otte, otte syv, syv to fire fem, fem en nul, fem"
+    );
+  }
+
+  #[test]
+  fn isbn_without_hyphens_keeps_prefix_body_and_check_digit_apart() {
+    assert_eq!(
+      danish_isbn("9788772455105").unwrap(),
+      "ni syv otte, otte syv syv to fire fem fem en nul, fem"
+    );
+  }
+
+  #[test]
+  fn malformed_isbn_is_an_error() {
+    assert_eq!(danish_isbn("978-87-7245-510"), Err(DanishError::InvalidLength { expected: 13, found: 12 }));
+    assert_eq!(danish_isbn("978-87-7245-51X-5"), Err(DanishError::InvalidDigit('X')));
+    assert_eq!(danish_isbn("978-87-7245-510-1"), Err(DanishError::InvalidCheckDigit));
+    assert_eq!(danish_isbn("978--877245-510-5"), Err(DanishError::InvalidDigit('-')));
+  }
+
+  #[test]
+  fn zero_padded_keeps_leading_zeros() {
+    assert_eq!(danish_zero_padded("007").unwrap(), "nul nul syv");
+    assert_eq!(danish_zero_padded("-007").unwrap(), "minus nul nul syv");
+    assert_eq!(danish_zero_padded("0").unwrap(), "nul");
+    assert_eq!(danish_zero_padded("42").unwrap(), "toogfyrre");
+    assert_eq!(danish_zero_padded("-"), Err(DanishError::EmptyInput));
+  }
+
+  #[test]
+  fn check_digit_can_be_flagged() {
+    assert_eq!(danish_digit_sequence_checked("4567", true).unwrap(), "fire fem seks, kontrolciffer syv");
+    assert_eq!(danish_digit_sequence_checked("4567", false).unwrap(), "fire fem seks syv");
+    assert_eq!(danish_digit_sequence_checked("7", true).unwrap(), "kontrolciffer syv");
+  }
+
+  #[test]
+  fn padded_names_the_number_after_its_zeros() {
+    assert_eq!(danish_padded(42, 3), "nul toogfyrre");
+    assert_eq!(danish_padded(5, 3), "nul nul fem");
+    assert_eq!(danish_padded(-42, 3), "minus nul toogfyrre");
+    assert_eq!(danish_padded(1234, 3), "et tusind to hundrede og fireogtredive");
+  }
+
+  #[test]
+  fn digit_runs_collapse_doubles_and_triples() {
+    assert_eq!(danish_digit_sequence_runs("55").unwrap(), "dobbelt fem");
+    assert_eq!(danish_digit_sequence_runs("5554").unwrap(), "tredobbelt fem fire");
+    assert_eq!(danish_digit_sequence_runs("1223334444").unwrap(), "en dobbelt to tredobbelt tre fire fire fire fire");
+  }
+
+  #[test]
+  fn digit_runs_longer_than_three_are_read_digit_by_digit() {
+    assert_eq!(danish_digit_sequence_runs("5555").unwrap(), "fem fem fem fem");
+  }
+
+  #[test]
+  fn digit_word_names_single_digits() {
+    assert_eq!(digit_word(0), Some("nul"));
+    assert_eq!(digit_word(9), Some("ni"));
+    assert_eq!(digit_word(10), None);
+  }
+
+  #[test]
+  fn triplets_are_grouped_from_the_right() {
+    assert_eq!(danish_triplets("123456789").unwrap(), "et hundrede og treogtyve, fire hundrede og seksoghalvtreds, syv hundrede og niogfirs");
+    assert_eq!(danish_triplets("12345").unwrap(), "tolv, tre hundrede og femogfyrre");
+    assert_eq!(danish_triplets(""), Err(DanishError::EmptyInput));
+    assert_eq!(danish_triplets("12a"), Err(DanishError::InvalidDigit('a')));
+  }
+}
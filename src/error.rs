@@ -0,0 +1,73 @@
+//
+// IMPORTS
+//
+
+use std::fmt;
+
+use crate::{format_danish_grouped, GroupStyle};
+
+//
+// ERRORS
+//
+
+// The error type shared by all the fallible readers in this crate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DanishError {
+  EmptyInput,                                     // Nothing to read
+  InvalidDigit(char),                             // A character that is not a digit where a digit was expected
+  InvalidLength { expected: usize, found: usize },// Wrong number of digits
+  InvalidCheckDigit,                              // The digits are fine, but the check digit does not add up
+  InvalidNumber(String),                          // Input that could not be parsed as a number
+  OutOfRange,                                     // A number outside what a reader can handle
+  TooLarge(String),                               // A number beyond MAX_NAMEABLE, in grouped digits like "1.000.000"
+  NonFinite,                                      // NaN or infinity
+  ZeroDenominator,                                // A fraction with nothing to divide by
+  InvalidRange                                    // A range that ends before it starts
+}
+
+// The errors of converting input to a Danish compound numeral name
+pub type ConvertError = DanishError;
+
+impl fmt::Display for DanishError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DanishError::EmptyInput => write!(f, "Empty input"),
+      DanishError::InvalidDigit(c) => write!(f, "Invalid digit '{c}'"),
+      DanishError::InvalidLength { expected, found } => write!(f, "Expected {expected} digits, found {found}"),
+      DanishError::InvalidCheckDigit => write!(f, "Invalid check digit"),
+      DanishError::InvalidNumber(input) => write!(f, "Invalid number '{input}'"),
+      DanishError::OutOfRange => write!(f, "Number is out of range"),
+      DanishError::TooLarge(digits) => write!(f, "Number {digits} is too large to be named"),
+      DanishError::NonFinite => write!(f, "Number is not finite"),
+      DanishError::ZeroDenominator => write!(f, "Denominator is zero"),
+      DanishError::InvalidRange => write!(f, "Range ends before it starts")
+    }
+  }
+}
+
+impl DanishError {
+  // Returns the error of a number beyond MAX_NAMEABLE, given as its digits with an optional minus sign
+  pub(crate) fn too_large(digits: &str) -> Self {
+    let (sign, digits) = digits.strip_prefix('-').map_or(("", digits), |digits| ("-", digits));
+    let digits = digits.trim_start_matches('0');
+    DanishError::TooLarge(format_danish_grouped(&format!("{sign}{digits}"), GroupStyle::Dot))
+  }
+}
+
+impl std::error::Error for DanishError {}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn too_large_shows_grouped_digits() {
+    let error = DanishError::too_large("-0001000000000000000000000000000000000000000");
+    assert_eq!(error, DanishError::TooLarge("-1.000.000.000.000.000.000.000.000.000.000.000.000.000".to_string()));
+    assert_eq!(error.to_string(), "Number -1.000.000.000.000.000.000.000.000.000.000.000.000.000 is too large to be named");
+  }
+}
@@ -0,0 +1,190 @@
+//
+// IMPORTS
+//
+
+use std::fmt;
+
+use crate::number::pad_name;
+use crate::{danish_ordinal_name, split_float, DanishCompoundNumeral, DanishError, AND, MINUS, NUMBER_NAMES};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+const HALF: &str = "halv";
+const HALVES: &str = "halve";
+const PART: &str = "del";   // "tredjedel", a third
+const PARTS: &str = "dele"; // "tredjedele", thirds
+
+//
+// DANISH FRACTION
+//
+
+// A fraction that displays as its Danish name, always kept in lowest terms with a positive denominator
+// println!("{}", DanishFraction::try_new(2, 3)?) -> "to tredjedele"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DanishFraction {
+  num: i128,
+  den: i128
+}
+
+// Returns the greatest common divisor of two numbers
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+  while b != 0 { (a, b) = (b, a % b); }
+  a
+}
+
+impl DanishFraction {
+  // Returns the fraction in lowest terms, 2/4 -> 1/2
+  // A zero denominator is an error, and so is a fraction whose reduced terms do not fit in an i128
+  pub fn try_new(num: i128, den: i128) -> Result<Self, DanishError> {
+    if den == 0 { return Err(DanishError::ZeroDenominator); }
+
+    let divisor = gcd(num.unsigned_abs(), den.unsigned_abs());
+    let (num_abs, den_abs) = (num.unsigned_abs() / divisor, den.unsigned_abs() / divisor);
+
+    // The sign of the fraction is kept on the numerator
+    let num = if (num < 0) != (den < 0) { 0_i128.checked_sub_unsigned(num_abs) } else { i128::try_from(num_abs).ok() };
+    let den = i128::try_from(den_abs).ok();
+    match (num, den) {
+      (Some(num), Some(den)) => Ok(DanishFraction { num, den }),
+      _ => Err(DanishError::OutOfRange)
+    }
+  }
+
+  // Returns the numerator, which carries the sign of the fraction
+  pub fn num(&self) -> i128 { self.num }
+
+  // Returns the denominator, which is always positive
+  pub fn den(&self) -> i128 { self.den }
+}
+
+// Like DanishNumber, a width pads the name like a string - "{:>20}"
+impl fmt::Display for DanishFraction {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.den == 1 { return pad_name(f, &self.num.danish_compound_numeral_name()); }
+
+    // "del" is common gender, so one part is "en" rather than "et". Other counts carry their own sign
+    let count = match self.num {
+      1 => NUMBER_NAMES[0][1].to_string(),
+      -1 => format!("{MINUS} {}", NUMBER_NAMES[0][1]),
+      num => num.danish_compound_numeral_name()
+    };
+
+    // Parts are named by the ordinal of the denominator, which has no "et" in front - "en hundrededel"
+    let part = match (self.den, self.num.unsigned_abs() == 1) {
+      (2, true) => HALF.to_string(),
+      (2, false) => HALVES.to_string(),
+      (den, one) => {
+        format!("{}{}", danish_ordinal_name(den), if one { PART } else { PARTS })
+      }
+    };
+
+    pad_name(f, &format!("{count} {part}"))
+  }
+}
+
+//
+// DECIMAL FRACTIONS
+//
+
+// Returns the Danish name of a float as a part of a power of ten, if it is exactly a tenth, hundredth, thousandth and so on
+// 0.001 -> "en tusindedel" rather than "nul komma nul, nul, en". Other floats are read like danish_compound_numeral_name
+// Only the powers whose part is a single word are named this way, so 0.0001 is read as decimals and not "en ti tusindedel"
+pub fn danish_decimal_fraction_name(number: f64) -> String {
+  let part = match split_float(number) {
+    Ok((0, Some(decimals))) if decimals.strip_suffix('1').is_some_and(|zeros| zeros.bytes().all(|b| b == b'0')) => {
+      10_i128.checked_pow(decimals.len() as u32).and_then(|den| DanishFraction::try_new(if number < 0.0 { -1 } else { 1 }, den).ok())
+    }
+    _ => None
+  };
+
+  match part.map(|part| part.to_string()) {
+    Some(name) if name.trim_start_matches(&format!("{MINUS} ")).split(' ').count() == 2 => name,
+    _ => number.danish_compound_numeral_name()
+  }
+}
+
+//
+// MIXED NUMBERS
+//
+
+// Returns the Danish name of a mixed number, a whole number and a fraction joined by "og" - 2, 1, 2 -> "to og en halv"
+// The whole number gives the sign, as in -2½, and the fraction is read in lowest terms like DanishFraction
+// Without a whole number only the fraction is read, and an improper fraction carries its whole parts over - 1, 7, 4 -> "to og tre fjerdedele"
+pub fn danish_mixed(whole: i128, num: i128, den: i128) -> Result<String, DanishError> {
+  if den == 0 { return Err(DanishError::ZeroDenominator); }
+  let negative = whole < 0 || (whole == 0 && (num < 0) != (den < 0));
+
+  // The fraction is read by its magnitude, with anything above one carried over to the whole number
+  let (num, den) = (num.unsigned_abs(), den.unsigned_abs());
+  let whole = whole.unsigned_abs().checked_add(num / den).and_then(|whole| i128::try_from(whole).ok()).ok_or(DanishError::OutOfRange)?;
+  let fraction = DanishFraction::try_new((num % den) as i128, i128::try_from(den).map_err(|_| DanishError::OutOfRange)?)?;
+
+  let name = match (whole, fraction.num()) {
+    (_, 0) => whole.danish_compound_numeral_name(),
+    (0, _) => fraction.to_string(),
+    // A whole one is counted like the parts, "en og en tredjedel"
+    (1, _) => format!("{} {AND} {fraction}", NUMBER_NAMES[0][1]),
+    _ => format!("{} {AND} {fraction}", whole.danish_compound_numeral_name())
+  };
+  Ok(if negative && (whole, fraction.num()) != (0, 0) { format!("{MINUS} {name}") } else { name })
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fractions_are_kept_in_lowest_terms() {
+    let fraction = DanishFraction::try_new(4, -6).unwrap();
+    assert_eq!((fraction.num(), fraction.den()), (-2, 3));
+    assert_eq!(DanishFraction::try_new(2, 4), DanishFraction::try_new(1, 2));
+    assert_eq!(DanishFraction::try_new(1, 0), Err(DanishError::ZeroDenominator));
+  }
+
+  #[test]
+  fn fractions_display_their_names() {
+    assert_eq!(DanishFraction::try_new(2, 4).unwrap().to_string(), "en halv");
+    assert_eq!(DanishFraction::try_new(3, 4).unwrap().to_string(), "tre fjerdedele");
+    assert_eq!(DanishFraction::try_new(-1, 3).unwrap().to_string(), "minus en tredjedel");
+    assert_eq!(DanishFraction::try_new(1, 100).unwrap().to_string(), "en hundrededel");
+    assert_eq!(DanishFraction::try_new(10, 2).unwrap().to_string(), "fem");
+  }
+
+  #[test]
+  fn fractions_honour_width_fill_and_alignment() {
+    assert_eq!(format!("[{:>10}]", DanishFraction::try_new(1, 2).unwrap()), "[   en halv]");
+    assert_eq!(format!("[{:-<6}]", DanishFraction::try_new(5, 1).unwrap()), "[fem---]");
+  }
+
+  #[test]
+  fn negative_powers_of_ten_are_named_as_parts() {
+    assert_eq!(danish_decimal_fraction_name(0.1), "en tiendedel");
+    assert_eq!(danish_decimal_fraction_name(0.01), "en hundrededel");
+    assert_eq!(danish_decimal_fraction_name(0.001), "en tusindedel");
+    assert_eq!(danish_decimal_fraction_name(-0.1), "minus en tiendedel");
+  }
+
+  #[test]
+  fn other_decimals_fall_back_to_the_decimal_reader() {
+    assert_eq!(danish_decimal_fraction_name(0.25), "nul komma to, fem");
+  }
+
+  #[test]
+  fn mixed_numbers_join_the_whole_and_the_fraction() {
+    assert_eq!(danish_mixed(2, 1, 2).unwrap(), "to og en halv");
+    assert_eq!(danish_mixed(-2, 1, 2).unwrap(), "minus to og en halv");
+    assert_eq!(danish_mixed(0, 3, 4).unwrap(), "tre fjerdedele");
+  }
+
+  #[test]
+  fn improper_mixed_numbers_carry_their_whole_parts_over() {
+    assert_eq!(danish_mixed(1, 7, 4).unwrap(), "to og tre fjerdedele");
+    assert_eq!(danish_mixed(2, 1, 0), Err(DanishError::ZeroDenominator));
+  }
+}
@@ -0,0 +1,1028 @@
+//
+// MODULES
+//
+
+mod address;
+mod cache;
+mod column;
+mod config;
+mod convert;
+mod count;
+mod currency;
+mod digits;
+mod error;
+mod fraction;
+mod number;
+mod ordinal;
+mod parse;
+mod score;
+mod small;
+mod text;
+mod time;
+mod tokens;
+mod write;
+
+use std::borrow::Cow;
+
+use convert::danish_too_large;
+
+pub use address::{danish_floor_door, danish_house_number};
+pub use cache::DanishCache;
+pub use column::{danish_column, Align};
+pub use config::{Case, DanishConfig, DecimalMode, GroupStyle, OneForm};
+pub use convert::{danish_decimal_string, danish_non_finite, parse_and_convert, parse_and_convert_with, parse_integer};
+pub use count::{danish_approximate, danish_definite_noun, danish_magnitude_count, danish_percent, danish_repeat_noun, danish_si, danish_temperature, danish_vague_count, danish_with_unit, DanishUnit, Qualifier, TemperatureStyle, Unit, VagueConfig};
+pub use currency::{danish_currency, danish_currency_config, danish_currency_with, CurrencySign};
+pub use digits::{danish_digit_sequence, danish_digit_sequence_checked, danish_digit_sequence_runs, danish_isbn, danish_padded, danish_triplets, danish_zero_padded, digit_word};
+pub use error::{ConvertError, DanishError};
+pub use fraction::{danish_decimal_fraction_name, danish_mixed, DanishFraction};
+pub use number::DanishNumber;
+pub use ordinal::{danish_century, danish_decade, danish_ordinal_name, danish_ordinal_name_gendered, danish_ordinal_range, danish_regnal, Gender};
+pub use parse::{danish_number_tokens, from_danish, from_danish_f64, DanishParseError, ParseErrorKind};
+pub use score::{danish_comparison, danish_ratio, danish_score, ScoreStyle};
+pub use small::danish_small;
+pub use text::humanize_text;
+pub use time::{danish_colloquial_minutes, danish_duration, danish_week};
+pub use tokens::{danish_tokens, DanishToken};
+pub use write::{write_danish, write_danish_with};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+const AND: &str = "og";
+const PLURAL_SUFFIX: &str = "er"; // The plural suffix of orders of magnitude like millions or billions - "million(er)" or "milliard(er)"
+
+const MINUS: &str = "minus";
+pub const DECIMAL_SEPARATOR: &str = "komma";
+#[deprecated(note = "misspelled, use DECIMAL_SEPARATOR")]
+pub const DECIMAL_SEPERATOR: &str = DECIMAL_SEPARATOR;
+
+// Forms of "one"
+const NEUTER_ONE: &str = "et"; // The neuter gender of "one" in Danish
+const EMPH_ONE: &str = "én";   // Emphasised "one", to distinguish from indefinite article "en"
+const EMPH_NEUTER_ONE: &str = "ét"; // Emphasised neuter "one"
+
+const HUNDRED: &str = "hundrede";
+
+// The names of the places of a number, ones first - (singular, plural, neuter)
+const PLACE_NAMES: &[(&str, &str, bool)] = &[
+  ("ener", "enere", false),
+  ("tier", "tiere", false),
+  ("hundrede", "hundreder", true),
+  ("tusind", "tusinder", true),
+  ("titusind", "titusinder", true),
+  ("hundredetusind", "hundredetusinder", true)
+];
+
+// Numbers below this have a name for each of their places
+pub const PLACE_VALUE_LIMIT: i128 = 1_000_000;
+
+const DECIMAL_PAUSE: &str = ", "; // Spoken pause between the decimals read digit by digit - "komma to, fem"
+
+// The most decimals DecimalMode::Whole reads as one number, like the øre of an amount - 3.45 -> "tre komma femogfyrre"
+pub const WHOLE_DECIMALS_LIMIT: usize = 2;
+
+// The established "half" compounds, (value, name). "halvanden" is one and a half, "halvtredje" two and a half
+const HALF_COMPOUNDS: &[(f64, &str)] = &[(1.5, "halvanden"), (2.5, "halvtredje")];
+
+// Round numbers with a colloquial name as half of an order of magnitude
+const HALF_IDIOMS: &[(u128, &str)] = &[(50, "et halvt hundrede"), (500, "et halvt tusinde")];
+
+const NUMBER_NAMES: &[&[&str]] = &[
+  &[
+    "nul",
+    "en",
+    "to",
+    "tre",
+    "fire",
+    "fem",
+    "seks",
+    "syv",
+    "otte",
+    "ni" // NAJNE!
+  ],
+  &[
+    "ti",
+    "elleve",
+    "tolv",
+    "tretten",
+    "fjorten",
+    "femten",
+    "seksten",
+    "sytten",
+    "atten",
+    "nitten"
+  ],
+  &[
+    "tyve",
+    "tredive",
+    "fyrre",
+    "halvtreds",
+    "tres",
+    "halvfjerds",
+    "firs",
+    "halvfems"
+  ],
+  &[
+    "tusind",
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "kvadrillion",
+    "kvadrilliard",
+    "kvintillion",
+    "kvintilliard",
+    "sekstillion"
+  ]
+];
+
+// Each order of magnitude in NUMBER_NAMES[3] with its power of ten, built at compile time
+const SCALE_TABLE: [(&str, u32); NUMBER_NAMES[3].len()] = {
+  let mut table = [("", 0); NUMBER_NAMES[3].len()];
+  let mut i = 0;
+  while i < table.len() {
+    table[i] = (NUMBER_NAMES[3][i], 3 * (i as u32 + 1));
+    i += 1;
+  }
+  table
+};
+
+//
+// SCRIPT STARTS HERE
+//
+
+// The sign of a number, for rendering the sign apart from the spelled magnitude
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+  Negative,
+  Zero,
+  Positive
+}
+
+// Conversion is cheap and synchronous. An i128 has at most 13 thousands' groups, so the worst case
+// (i128::MAX) is a bounded handful of small string joins - a matter of microseconds - and there is nothing to yield on
+pub trait DanishCompoundNumeral {
+  fn danish_compound_numeral_name(&self) -> String {
+    self.danish_compound_numeral_name_with(&DanishConfig::default())
+  }
+
+  // Same as danish_compound_numeral_name, but spelled according to the given config
+  fn danish_compound_numeral_name_with(&self, config: &DanishConfig) -> String;
+
+  // Same as danish_compound_numeral_name, but fails instead of naming a number it cannot represent faithfully
+  fn try_danish_compound_numeral_name(&self) -> Result<String, DanishError> {
+    self.try_danish_compound_numeral_name_with(&DanishConfig::default())
+  }
+
+  // Same as try_danish_compound_numeral_name, but spelled according to the given config
+  fn try_danish_compound_numeral_name_with(&self, config: &DanishConfig) -> Result<String, DanishError> {
+    Ok(self.danish_compound_numeral_name_with(config))
+  }
+
+  // Returns the sign of the number along with the name of its magnitude, (Sign::Negative, "fem") for -5
+  fn danish_signed(&self) -> (Sign, String);
+
+  // Returns the name of the magnitude alone, never with a "minus" - -5 and 5 are both "fem"
+  // For showing the sign apart from the name, like as an icon
+  fn magnitude_spelled(&self) -> String {
+    self.danish_signed().1
+  }
+}
+
+// Readings that only make sense for integers
+pub trait DanishIntegerNumeral {
+  // Returns the name of each thousands' group, most significant first, as they are joined in the full name
+  // 7_023_461 -> ["syv millioner", "treogtyve tusind", "fire hundrede og enogtres"]. The "og" joining the last group is left out
+  // The sign is not part of any group
+  fn danish_groups(&self) -> Vec<String>;
+
+  // Returns the name of each thousands' group like danish_groups, but the least significant first
+  // 7_023_461 -> ["fire hundrede og enogtres", "treogtyve tusind", "syv millioner"]
+  fn danish_groups_reversed(&self) -> Vec<String>;
+
+  // Returns the most significant order of magnitude in words and the rest as digits grouped with dots, for dashboards
+  // 2_345_678 -> "2 millioner 345.678". Numbers below 1000 are just their digits
+  fn danish_scaled_digits(&self) -> String;
+
+  // Returns whether the name of the number ends in the emphasised "én", as 101 "et hundrede og én" does
+  // 1 on its own is "et" and 21 is "enogtyve", so neither uses it
+  fn uses_emphasised_one(&self) -> bool;
+
+  // Returns the digits of the number by their place values, for teaching - 204 -> "to hundreder, nul tiere, fire enere"
+  // Places that are zero are kept, as they are the point of the exercise. Only defined from 0 to PLACE_VALUE_LIMIT - 1
+  fn danish_place_value(&self) -> Result<String, DanishError>;
+
+  // Returns the name like danish_compound_numeral_name, but borrowed from the table of danish_small for 0 to 99
+  // Only larger and negative numbers allocate
+  fn danish_cow(&self) -> Cow<'static, str>;
+
+  // Returns both the name and the digits grouped with dots, for tables - 7_023_461 -> ("syv millioner ...", "7.023.461")
+  // The number is split into its thousands' groups once for both
+  fn spelled_and_grouped(&self) -> (String, String);
+
+  // Returns the index of the most significant thousands' group, 0 below 1000, 1 for thousands, 2 for millions and so on
+  // Group i > 0 is named by NUMBER_NAMES[3][i - 1], so scale_table().len() is the largest index that can be named
+  fn required_scale_index(&self) -> usize;
+
+  // Returns the length in bytes of danish_compound_numeral_name, without building the name
+  // For sizing a buffer up front, so the name can be written with a single allocation
+  fn danish_len(&self) -> usize;
+}
+
+// Returns every order of magnitude that can be named with its power of ten, [("tusind", 3), ("million", 6), ...]
+pub fn scale_table() -> &'static [(&'static str, u32)] {
+  &SCALE_TABLE
+}
+
+// Returns the order of magnitude named by a power of ten, 9 -> Some("milliard")
+// Only multiples of three from 3 up are orders of magnitude, so 0, 4 and powers too large to name give None
+pub fn scale_word_for_exponent(exponent: u32) -> Option<&'static str> {
+  if exponent == 0 || !exponent.is_multiple_of(3) { return None; }
+  NUMBER_NAMES[3].get(exponent as usize / 3 - 1).copied()
+}
+
+// Returns the n'th digit of an integer
+fn nth_digit(number: u128, n: u32) -> u128 {
+  number / 10_u128.pow(n - 1) % 10
+}
+
+// Returns a string of digits grouped by thousands in the given style - "345678" -> "345.678" with dots
+// A leading minus sign stays in front of the groups
+pub(crate) fn format_danish_grouped(digits: &str, style: GroupStyle) -> String {
+  let (sign, digits) = digits.strip_prefix('-').map_or(("", digits), |digits| ("-", digits));
+  let separator = style.separator();
+  let mut string = String::with_capacity(sign.len() + digits.len() + digits.len() / 3 * separator.len());
+  string.push_str(sign);
+  for (i, digit) in digits.chars().enumerate() {
+    if i > 0 && (digits.len() - i).is_multiple_of(3) { string.push_str(separator); }
+    string.push(digit);
+  }
+  string
+}
+
+// Returns an integer written in digits grouped by thousands, 1234567 -> "1.234.567" with GroupStyle::Dot
+pub fn danish_grouped(number: i128, style: GroupStyle) -> String {
+  format_danish_grouped(&number.to_string(), style)
+}
+
+// Returns the singular name of an order of magnitude by its index in NUMBER_NAMES[3]
+// The word for thousand is configurable, so every spelling of a thousands' group must go through here
+fn scale_word(index: usize, config: &DanishConfig) -> &'static str {
+  if index == 0 { config.thousand } else { NUMBER_NAMES[3][index] }
+}
+
+// Returns the thousands' groups of a number, the least significant group first
+fn thousands_groups(number: u128) -> Vec<u128> {
+  // Take the number 7_023_461 as an example. It is essentially just made up of what we call it:
+  // 7 millions, 23 thousands, and 461 (ones)
+
+  // Construct a list of digits grouped by thousands
+  // The above example of 7_023_461 would for an example become
+  // -> [461, 23, 7]
+  let mut digits_by_thousands = vec![];
+  let mut n = number;
+  while n > 0 {
+    digits_by_thousands.push(n % 1000);
+    n /= 1000;
+  }
+  digits_by_thousands
+}
+
+// Returns the names of each thousands' group of a number >= 1000 with their orders of magnitude
+// The groups come from thousands_groups, and the least significant group comes first
+fn group_names(digits_by_thousands: &[u128], config: &DanishConfig) -> Vec<String> {
+  let mut strings = vec![];
+  for (i, digits) in digits_by_thousands.iter().enumerate() {
+    if *digits == 0 { continue; } // If group has no digits -> continue
+
+    // Get numeral name of digits
+    let mut string = magnitude_name(*digits, config);
+
+    // We inject an "and" if we are on the first group and the group value is < 100
+    // We also inject an "and" if there are no digits in the thousands' group
+    // This is to eliminate cases of a missing stringing "and" when we have group-sized gaps in numbers like
+    // 1_000_001, 1_000_000_001 or 1_000_000_000_001 etc.
+    if i == 0 && (*digits < 100 || *(digits_by_thousands.get(1).unwrap_or(&1)) == 0) {
+      if *digits == 1 { string = config.one_form.emphasised().to_string() }
+      // With a single final "and" we do not want another one if the group already has one after its hundreds
+      if !(config.single_final_og && string.contains(&format!(" {AND} "))) { string = format!("{AND} {string}") }
+    }
+
+    // With a single final "and" only the last group with digits may keep the "and" after its hundreds
+    if config.single_final_og && digits_by_thousands[..i].iter().any(|d| *d > 0) {
+      string = string.replacen(&format!(" {AND} "), " ", 1);
+    }
+    
+    // Eliminates cases of wrong gender of definite article
+    // Only "thousands" is neuter gender
+    if i > 0 && *digits == 1 { string = config.scale_one(i == 1).to_string(); }
+
+    // The same goes for a group ending in one, like 101_000. One is not emphasised in front of an order of magnitude
+    // -> "et hundrede og et tusind" and "et hundrede og en millioner"
+    if i > 0 && *digits > 100 && digits % 100 == 1 {
+      if let Some(rest) = string.strip_suffix(config.one_form.emphasised()) {
+        string = format!("{rest}{}", config.scale_one(i == 1));
+      }
+    }
+
+    strings.push(if i > 0 { 
+      format!("{string}{}{}{}",
+        if i == 1 && config.compound_thousands { "" } else { " " },
+        scale_word(i - 1, config), // Injects order of magnitude
+        if i > 1 && *digits > 1 { PLURAL_SUFFIX } else { "" } // Injects plural suffix where needed. Importantly thousands' do not need a suffix
+      )
+    } else { string })
+  }
+
+  strings
+}
+
+// Returns the name of the numbers that are a single word straight from the lists, 0 to 19
+// This saves the small numbers, which are by far the most common, from the formatting below
+fn small_name(number: u128, config: &DanishConfig) -> Option<&'static str> {
+  match number {
+    1 => Some(config.one_form.standalone()),
+    0..=9 => Some(NUMBER_NAMES[0][number as usize]),
+    10..=19 => Some(NUMBER_NAMES[1][number as usize - 10]),
+    _ => None
+  }
+}
+
+// Returns the Danish compound numeral name of the magnitude of a number
+// It is unsigned, as the magnitude of i128::MIN does not fit in an i128
+fn magnitude_name(number: u128, config: &DanishConfig) -> String {
+  if let Some(name) = small_name(number, config) { return name.to_string(); }
+
+  // Numbers below 20 are easy, small_name takes them straight from the lists
+  // Numbers below 1000 are more complicated, yet still relatively simple
+  // We treat any such number as three digits. Sometimes requiring left-padding of zeros
+  // We evaluate the hundreds' place first, then the tens' and ones' together
+  if number < 1000 {
+    let hundreds = nth_digit(number, 3) as usize; // Digit in the hundreds' place
+    let tens = nth_digit(number, 2) as usize;     // Digit in the tens' place
+    let ones = nth_digit(number, 1) as usize;     // Digit in the ones' place
+
+    return format!("{}{}{}",
+      if hundreds > 0 { // If there is something in the hundreds' place, isert it into the string
+        format!("{} {}", if hundreds == 1 { config.scale_one(true) } else { NUMBER_NAMES[0][hundreds] }, config.hundred)
+      } else { String::new() }, // Else insert an empty string
+      // If there is something in the hundreds' place and tens' and/or ones' place, inject an "and" after the hundreds
+      if tens + ones > 0 && hundreds > 0 { format!(" {AND} ") } else { String::new() },
+      {
+        if tens == 0 { // If thre is nothing in the tens' place
+          (
+            if ones == 0 { "" }                                 // zero -> Empty string
+            else if ones == 1 { config.one_form.emphasised() }  // one  -> Emphasised one
+            else { NUMBER_NAMES[0][ones] }                      // n    -> Name of n
+          ).to_string()
+        } else if tens == 1 { // Teens
+          NUMBER_NAMES[1][ones].to_string()
+        } else if ones == 0 { // Only tens' place name
+          NUMBER_NAMES[2][tens - 2].to_string()
+        } else if config.hyphenate_compounds { // Hyphenated compound of ones and tens
+          format!("{}-{AND}-{}", NUMBER_NAMES[0][ones], NUMBER_NAMES[2][tens - 2])
+        } else { // Compound of ones and tens
+          format!("{}{AND}{}", NUMBER_NAMES[0][ones], NUMBER_NAMES[2][tens - 2])
+        }
+      }
+    );
+  }
+
+  // At this point we must have a number that is numerically greater than or equal to 1000
+  // This means we can construct a compound number by splitting it into thousands and
+  // feeding the groups into this very function
+  let mut strings = group_names(&thousands_groups(number), config);
+
+  // Reverses the list, as up until now we have actually been working in reverse
+  strings.reverse();
+
+  // Finally we return our joined list
+  strings.join(config.group_separator)
+}
+
+// Returns the length in bytes of the name magnitude_name gives with the default config, without building it
+// Each step mirrors magnitude_name and group_names, so the two must be changed together
+fn magnitude_name_len(number: u128) -> usize {
+  let config = DanishConfig::default();
+  if let Some(name) = small_name(number, &config) { return name.len(); }
+
+  if number < 1000 {
+    let hundreds = nth_digit(number, 3) as usize;
+    let tens = nth_digit(number, 2) as usize;
+    let ones = nth_digit(number, 1) as usize;
+
+    let hundreds_len = if hundreds > 0 {
+      (if hundreds == 1 { config.scale_one(true) } else { NUMBER_NAMES[0][hundreds] }).len() + 1 + config.hundred.len()
+    } else { 0 };
+    let and_len = if tens + ones > 0 && hundreds > 0 { AND.len() + 2 } else { 0 };
+    let rest_len = match (tens, ones) {
+      (0, 0) => 0,
+      (0, 1) => config.one_form.emphasised().len(),
+      (0, _) => NUMBER_NAMES[0][ones].len(),
+      (1, _) => NUMBER_NAMES[1][ones].len(),
+      (_, 0) => NUMBER_NAMES[2][tens - 2].len(),
+      _ => NUMBER_NAMES[0][ones].len() + AND.len() + NUMBER_NAMES[2][tens - 2].len()
+    };
+    return hundreds_len + and_len + rest_len;
+  }
+
+  // The groups are walked least significant first like group_names, without collecting them
+  let mut len = 0;
+  let mut n = number;
+  let mut i = 0;
+  while n > 0 {
+    let digits = n % 1000;
+    if digits > 0 {
+      let mut group_len = magnitude_name_len(digits);
+      if i == 0 && (digits < 100 || (number / 1000).is_multiple_of(1000)) {
+        if digits == 1 { group_len = config.one_form.emphasised().len(); }
+        group_len += AND.len() + 1;
+      }
+      if i > 0 && digits == 1 { group_len = config.scale_one(i == 1).len(); }
+      if i > 0 && digits > 100 && digits % 100 == 1 {
+        group_len = group_len - config.one_form.emphasised().len() + config.scale_one(i == 1).len();
+      }
+      if i > 0 {
+        group_len += 1 + scale_word(i - 1, &config).len() + if i > 1 && digits > 1 { PLURAL_SUFFIX.len() } else { 0 };
+      }
+
+      if len > 0 { len += config.group_separator.len(); }
+      len += group_len;
+    }
+    n /= 1000;
+    i += 1;
+  }
+  len
+}
+
+// Returns the name of a magnitude that is the whole number, with the half idioms of the config
+// Only whole numbers get them, as "et halvt hundrede komma fem" is not said
+fn whole_name(number: u128, config: &DanishConfig) -> String {
+  match HALF_IDIOMS.iter().find(|(value, _)| *value == number) {
+    Some((_, idiom)) if config.half_idioms => idiom.to_string(),
+    _ => magnitude_name(number, config)
+  }
+}
+
+// Returns the Danish compound numeral name of an integer, before the case of the config is applied
+// Readers that build on the name of an integer use this, so the case is applied once to their whole output
+pub(crate) fn integer_name(number: i128, config: &DanishConfig) -> String {
+  // We do not *actually* care if a number is negative
+  // So let us name it as positive and deal with the negativity afterwards :)
+  let name = whole_name(number.unsigned_abs(), config);
+  if number < 0 { format!("{MINUS} {name}") } else { name }
+}
+
+impl DanishCompoundNumeral for i128 {
+  // Returns the Danish compound numeral name of a compound number
+  // (Works for non-compound numbers too)
+  fn danish_compound_numeral_name_with(&self, config: &DanishConfig) -> String {
+    config.finish(config.with_numeral(integer_name(*self, config), &self.to_string(), None))
+  }
+
+  fn danish_signed(&self) -> (Sign, String) {
+    let sign = match self.signum() {
+      -1 => Sign::Negative,
+      0 => Sign::Zero,
+      _ => Sign::Positive
+    };
+    (sign, magnitude_name(self.unsigned_abs(), &DanishConfig::default()))
+  }
+}
+
+impl DanishIntegerNumeral for i128 {
+  fn danish_groups(&self) -> Vec<String> {
+    let mut strings = self.danish_groups_reversed();
+    strings.reverse();
+    strings
+  }
+
+  fn danish_groups_reversed(&self) -> Vec<String> {
+    let number = self.unsigned_abs();
+    if number < 1000 { return vec![magnitude_name(number, &DanishConfig::default())]; }
+
+    // The groups are named least significant first, so this is the order before danish_groups reverses them
+    // The "og" that joins the last group to the rest belongs to the full name, not to the group - 1_000_001 -> ["én", "en million"]
+    let mut groups = group_names(&thousands_groups(number), &DanishConfig::default());
+    if let Some(joined) = groups[0].strip_prefix(&format!("{AND} ")) { groups[0] = joined.to_string(); }
+    groups
+  }
+
+  fn uses_emphasised_one(&self) -> bool {
+    // The ones' group is named with the emphasised one when it ends in a one without tens, but not when it is just 1
+    let number = self.unsigned_abs();
+    number > 1 && number % 100 == 1
+  }
+
+  fn required_scale_index(&self) -> usize {
+    match self.unsigned_abs() {
+      0 => 0,
+      number => (number.ilog10() / 3) as usize
+    }
+  }
+
+  fn danish_len(&self) -> usize {
+    let len = magnitude_name_len(self.unsigned_abs());
+    if *self < 0 { MINUS.len() + 1 + len } else { len }
+  }
+
+  fn spelled_and_grouped(&self) -> (String, String) {
+    let number = self.unsigned_abs();
+    let sign = if *self < 0 { "-" } else { "" };
+    if number < 1000 { return (self.danish_compound_numeral_name(), format!("{sign}{number}")); }
+
+    let groups = thousands_groups(number);
+    let mut strings = group_names(&groups, &DanishConfig::default());
+    strings.reverse();
+    let name = strings.join(" ");
+
+    // The most significant group is written as it is, and the rest padded to three digits
+    let mut digits = format!("{sign}{}", groups[groups.len() - 1]);
+    for group in groups.iter().rev().skip(1) { digits.push_str(&format!(".{group:03}")); }
+
+    (if *self < 0 { format!("{MINUS} {name}") } else { name }, digits)
+  }
+
+  fn danish_cow(&self) -> Cow<'static, str> {
+    match u8::try_from(*self) {
+      Ok(number) if number < 100 => Cow::Borrowed(danish_small(number)),
+      _ => Cow::Owned(self.danish_compound_numeral_name())
+    }
+  }
+
+  fn danish_place_value(&self) -> Result<String, DanishError> {
+    if !(0..PLACE_VALUE_LIMIT).contains(self) { return Err(DanishError::OutOfRange); }
+
+    let digits = self.to_string();
+    let places = digits.bytes().rev().zip(PLACE_NAMES).rev().map(|(digit, (singular, plural, neuter))| {
+      match digit - b'0' {
+        1 => format!("{} {singular}", if *neuter { NEUTER_ONE } else { NUMBER_NAMES[0][1] }),
+        digit => format!("{} {plural}", NUMBER_NAMES[0][digit as usize])
+      }
+    });
+
+    Ok(places.collect::<Vec<String>>().join(", "))
+  }
+
+  fn danish_scaled_digits(&self) -> String {
+    let number = self.unsigned_abs();
+    let sign = if *self < 0 { "-" } else { "" };
+    if number < 1000 { return format!("{sign}{number}"); }
+
+    // The index of the most significant group, 1 for thousands, 2 for millions and so on
+    let top = self.required_scale_index();
+    let scale = 1000_u128.pow(top as u32);
+    let (count, rest) = (number / scale, number % scale);
+
+    let config = DanishConfig::default();
+    let mut string = format!("{sign}{count} {}{}", scale_word(top - 1, &config), if top > 1 && count > 1 { PLURAL_SUFFIX } else { "" });
+    if rest > 0 { string = format!("{string} {}", format_danish_grouped(&rest.to_string(), GroupStyle::Dot)); }
+    string
+  }
+}
+
+// Returns the name of the decimals of a number, given as the string of digits after the decimal point
+pub(crate) fn decimals_name(decimals: &str, config: &DanishConfig) -> String {
+  // In whole mode the decimals are read as the whole number they spell out, "45" -> "femogfyrre"
+  // Leading zeros are each read as "nul", so 3.05 is "tre komma nul fem" and not mistaken for 3.5
+  // Only short decimals like those of money are read whole. Longer ones are read digit by digit below
+  if config.decimal_mode == DecimalMode::Whole && decimals.len() <= WHOLE_DECIMALS_LIMIT {
+    let rest = decimals.trim_start_matches('0');
+    let zeros = vec![NUMBER_NAMES[0][0]; decimals.len() - rest.len()].join(" ");
+    match rest.parse::<u128>() {
+      _ if rest.is_empty() => return zeros,
+      Ok(number) if zeros.is_empty() => return magnitude_name(number, config),
+      Ok(number) => return format!("{zeros} {}", magnitude_name(number, config)),
+      Err(..) => {}
+    }
+  }
+
+  // In pairs mode each pair of decimals is read as a number, with a leading zero read as "nul" - "0576" -> "nul fem seksoghalvfjerds"
+  if config.decimal_mode == DecimalMode::Pairs {
+    return decimals.as_bytes()
+      .chunks(2)
+      .map(|pair| match pair {
+        [b'0', digit] => format!("{} {}", NUMBER_NAMES[0][0], NUMBER_NAMES[0][(digit - b'0') as usize]),
+        [tens, ones] => magnitude_name(((tens - b'0') * 10 + ones - b'0') as u128, config),
+        [digit] => NUMBER_NAMES[0][(digit - b'0') as usize].to_string(),
+        _ => String::new()
+      })
+      .collect::<Vec<String>>()
+      .join(" ");
+  }
+
+  // Each decimal is read on its own, and the names are joined with a spoken pause
+  // We explicitly use the NUMBER_NAMES list as we want the *raw* number name - zero included and no care for gender
+  decimals.chars()
+    .filter_map(|decimal| decimal.to_digit(10))
+    .map(|decimal| NUMBER_NAMES[0][decimal as usize])
+    .collect::<Vec<&str>>()
+    .join(DECIMAL_PAUSE)
+}
+
+// The largest magnitude that has a name. The most negative number, i128::MIN, is one further
+pub const MAX_NAMEABLE: i128 = i128::MAX;
+
+// The integer part of a float is named through i128, so its magnitude must stay below 2^127
+pub(crate) const FLOAT_INTEGER_LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+// Returns whether a float is negative, the magnitude of its integer part and its decimals, rounded to the given places
+// A float that rounds to zero, like -0.0 or -0.04 at one place, is not negative as there is no sign to read
+fn float_parts(number: f64, places: Option<usize>) -> Result<(bool, u128, Option<String>), DanishError> {
+  if !number.is_finite() { return Err(DanishError::NonFinite); }
+  if number.abs() >= FLOAT_INTEGER_LIMIT { return Err(DanishError::too_large(&format!("{:.0}", number.trunc()))); }
+
+  // Rust never formats floats with an exponent, so the string is always digits around an optional decimal point
+  let string = match places {
+    Some(places) => format!("{number:.places$}"),
+    None => number.to_string()
+  };
+  let (negative, unsigned) = match string.strip_prefix('-') {
+    Some(unsigned) => (true, unsigned),
+    None => (false, string.as_str())
+  };
+  let (integer, decimals) = match unsigned.split_once('.') {
+    Some((integer, decimals)) => (integer, Some(decimals)),
+    None => (unsigned, None)
+  };
+
+  let magnitude = integer.parse::<u128>().map_err(|_| DanishError::too_large(&string))?;
+  let zero = magnitude == 0 && decimals.is_none_or(|decimals| decimals.bytes().all(|b| b == b'0'));
+  Ok((negative && !zero, magnitude, decimals.map(str::to_string)))
+}
+
+// Returns the integer part of a float, truncated towards zero, and the digits after its decimal point if it has any
+// -3.25 -> (-3, Some("25")) and 4.0 -> (4, None). Floats that are not finite or too large to name are errors
+// The integer part of a number between -1 and 0 is 0, so its sign is only found on the float itself
+pub fn split_float(number: f64) -> Result<(i128, Option<String>), DanishError> {
+  let (negative, magnitude, decimals) = float_parts(number, None)?;
+  let integer = if negative { 0_i128.checked_sub_unsigned(magnitude) } else { i128::try_from(magnitude).ok() };
+  Ok((integer.ok_or_else(|| DanishError::too_large(&number.to_string()))?, decimals))
+}
+
+impl DanishCompoundNumeral for f64 {
+  // Returns the Danish compound numeral name of a compound floating point number
+  // (Works for non-compound numbers too)
+  // Floats that are not finite are read like danish_non_finite, and floats too large to name are "et for stort tal"
+  fn danish_compound_numeral_name_with(&self, config: &DanishConfig) -> String {
+    let (negative, magnitude, decimals) = match float_parts(*self, config.decimal_places) {
+      Ok(parts) => parts,
+      Err(DanishError::NonFinite) => return config.finish(danish_non_finite(*self).unwrap_or_default()),
+      Err(..) => return config.finish(danish_too_large(*self))
+    };
+
+    let name = match &decimals {
+      // With a fixed number of decimal places we keep any zeros the rounding leaves
+      Some(decimals) => format!("{} {} {}", magnitude_name(magnitude, config), config.decimal_separator, decimals_name(decimals, config)),
+      None => whole_name(magnitude, config)
+    };
+    let (name, sign) = if negative { (format!("{MINUS} {name}"), "-") } else { (name, "") };
+    config.finish(config.with_numeral(name, &format!("{sign}{magnitude}"), decimals.as_deref()))
+  }
+
+  // Floats too large for i128 have no exact name, so they are TooLarge errors here
+  fn try_danish_compound_numeral_name_with(&self, config: &DanishConfig) -> Result<String, DanishError> {
+    float_parts(*self, config.decimal_places)?;
+    Ok(self.danish_compound_numeral_name_with(config))
+  }
+
+  // -0.0 is zero like 0.0. NaN has no sign to speak of and counts as positive
+  fn danish_signed(&self) -> (Sign, String) {
+    let sign = if *self == 0.0 { Sign::Zero } else if *self < 0.0 { Sign::Negative } else { Sign::Positive };
+    (sign, self.abs().danish_compound_numeral_name())
+  }
+}
+
+// Readings that only make sense for floats
+pub trait DanishFloatNumeral {
+  // Returns the colloquial "half" compound of the number if it has an established one, "halvanden" for 1.5
+  // Other numbers are read like danish_compound_numeral_name - 3.5 -> "tre komma fem"
+  fn danish_half_compound(&self) -> String;
+
+  // Returns the name of the whole part of the number alone, floored towards negative infinity
+  // 3.9 -> "tre" and -3.1 -> "minus fire". Floats that are not finite or too large are read like danish_compound_numeral_name reads them
+  fn danish_whole_part(&self) -> String;
+}
+
+impl DanishFloatNumeral for f64 {
+  fn danish_half_compound(&self) -> String {
+    match HALF_COMPOUNDS.iter().find(|(value, _)| *value == self.abs()) {
+      Some((_, name)) if *self < 0.0 => format!("{MINUS} {name}"),
+      Some((_, name)) => name.to_string(),
+      None => self.danish_compound_numeral_name()
+    }
+  }
+
+  fn danish_whole_part(&self) -> String {
+    self.floor().danish_compound_numeral_name()
+  }
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn floats_too_large_are_not_named_as_another_number() {
+    assert_eq!(1e40.danish_compound_numeral_name(), "et for stort tal");
+    assert_eq!((-1e40).danish_compound_numeral_name(), "minus et for stort tal");
+    assert_eq!(1e40.danish_whole_part(), "et for stort tal");
+  }
+
+  #[test]
+  fn floats_too_large_are_errors_when_tried() {
+    assert!(matches!(1e40.try_danish_compound_numeral_name(), Err(DanishError::TooLarge(_))));
+    assert!(matches!((-1e40).try_danish_compound_numeral_name(), Err(DanishError::TooLarge(_))));
+    assert_eq!(1e30.try_danish_compound_numeral_name().unwrap(), 1e30.danish_compound_numeral_name());
+  }
+
+  #[test]
+  fn groups_are_spelled_most_significant_first() {
+    assert_eq!(7_023_461.danish_groups(), ["syv millioner", "treogtyve tusind", "fire hundrede og enogtres"]);
+  }
+
+  #[test]
+  fn groups_leave_out_the_joining_og() {
+    assert_eq!(1_000_001.danish_groups(), ["en million", "én"]);
+    assert_eq!(2_000_050.danish_groups(), ["to millioner", "halvtreds"]);
+    assert_eq!((-1_000_001).danish_groups_reversed(), ["én", "en million"]);
+  }
+
+  #[test]
+  fn signed_names_the_magnitude_alone() {
+    assert_eq!((-3).danish_signed(), (Sign::Negative, "tre".to_string()));
+    assert_eq!(0.danish_signed(), (Sign::Zero, "nul".to_string()));
+    assert_eq!(4.5.danish_signed(), (Sign::Positive, "fire komma fem".to_string()));
+    assert_eq!((-0.0).danish_signed(), (Sign::Zero, "nul".to_string()));
+  }
+
+  #[test]
+  fn teens_inside_higher_groups() {
+    assert_eq!(13_000.danish_compound_numeral_name(), "tretten tusind");
+    assert_eq!(1_013.danish_compound_numeral_name(), "et tusind og tretten");
+    assert_eq!(13_013.danish_compound_numeral_name(), "tretten tusind og tretten");
+    assert_eq!(113_013_013.danish_compound_numeral_name(), "et hundrede og tretten millioner tretten tusind og tretten");
+  }
+
+  #[test]
+  fn whole_decimals_are_spelled_by_the_config() {
+    let config = DanishConfig::new().decimal_mode(DecimalMode::Whole);
+    assert_eq!(3.25.danish_compound_numeral_name_with(&config), "tre komma femogtyve");
+    assert_eq!(3.05.danish_compound_numeral_name_with(&config), "tre komma nul fem");
+    let config = config.hyphenate_compounds(true);
+    assert_eq!(3.25.danish_compound_numeral_name_with(&config), "tre komma fem-og-tyve");
+  }
+
+  #[test]
+  fn half_compounds_use_the_established_forms() {
+    assert_eq!(1.5.danish_half_compound(), "halvanden");
+    assert_eq!(2.5.danish_half_compound(), "halvtredje");
+    assert_eq!((-1.5).danish_half_compound(), "minus halvanden");
+  }
+
+  #[test]
+  fn other_decimals_are_not_half_compounds() {
+    assert_eq!(3.5.danish_half_compound(), "tre komma fem");
+    assert_eq!(2.25.danish_half_compound(), "to komma to, fem");
+  }
+
+  #[test]
+  fn small_numbers_match_the_full_path() {
+    let names = ["nul", "et", "to", "tre", "fire", "fem", "seks", "syv", "otte", "ni", "ti", "elleve", "tolv", "tretten", "fjorten", "femten", "seksten", "sytten", "atten", "nitten"];
+    for (n, name) in names.iter().enumerate() { assert_eq!((n as i128).danish_compound_numeral_name(), *name); }
+    assert_eq!((-1).danish_compound_numeral_name(), "minus et");
+    assert_eq!(1.danish_compound_numeral_name_with(&DanishConfig::new().one_form(OneForm::Common)), "en");
+  }
+
+  #[test]
+  fn negative_groups_have_a_single_leading_minus() {
+    assert_eq!((-7_023_461).danish_compound_numeral_name(), "minus syv millioner treogtyve tusind fire hundrede og enogtres");
+    assert_eq!((-1_000_000).danish_compound_numeral_name(), "minus en million");
+    assert_eq!((-2_000_001).danish_compound_numeral_name(), "minus to millioner og én");
+    for n in [-7_023_461, -1_000_000, -2_000_001, i128::MIN] {
+      let name = n.danish_compound_numeral_name();
+      assert_eq!(name.matches(MINUS).count(), 1, "{name}");
+      assert!(!name.contains("  ") && name.trim() == name, "{name:?}");
+    }
+  }
+
+  #[test]
+  fn scaled_digits_name_the_top_group_only() {
+    assert_eq!(2_345_678.danish_scaled_digits(), "2 millioner 345.678");
+    assert_eq!((-2_345_678).danish_scaled_digits(), "-2 millioner 345.678");
+    assert_eq!(1_000_000_001.danish_scaled_digits(), "1 milliard 1");
+    assert_eq!(7_000_000.danish_scaled_digits(), "7 millioner");
+    assert_eq!(999.danish_scaled_digits(), "999");
+  }
+
+  #[test]
+  fn emphasised_one_matches_the_spelling() {
+    assert!(!1.uses_emphasised_one());
+    assert!(!21.uses_emphasised_one());
+    assert!(!100.uses_emphasised_one());
+    assert!(101.uses_emphasised_one());
+    assert!((-1_000_001).uses_emphasised_one());
+    for n in -3_000..3_000 {
+      assert_eq!(n.uses_emphasised_one(), n.danish_compound_numeral_name().ends_with(EMPH_ONE), "{n}");
+    }
+  }
+
+  #[test]
+  fn scale_table_pairs_words_with_exponents() {
+    assert_eq!(&scale_table()[..4], [("tusind", 3), ("million", 6), ("milliard", 9), ("billion", 12)]);
+  }
+
+  #[test]
+  fn place_values_name_each_place() {
+    assert_eq!(234.danish_place_value().unwrap(), "to hundreder, tre tiere, fire enere");
+    assert_eq!(204.danish_place_value().unwrap(), "to hundreder, nul tiere, fire enere");
+    assert_eq!(1_011.danish_place_value().unwrap(), "et tusind, nul hundreder, en tier, en ener");
+  }
+
+  #[test]
+  fn place_values_are_limited_to_non_negative_numbers_below_the_limit() {
+    assert!(999_999.danish_place_value().is_ok());
+    assert_eq!(PLACE_VALUE_LIMIT.danish_place_value(), Err(DanishError::OutOfRange));
+    assert_eq!((-1).danish_place_value(), Err(DanishError::OutOfRange));
+  }
+
+  #[test]
+  fn cow_borrows_small_names() {
+    assert!(matches!(7.danish_cow(), Cow::Borrowed("syv")));
+    assert!(matches!(19.danish_cow(), Cow::Borrowed("nitten")));
+    assert!(matches!(1_234.danish_cow(), Cow::Owned(..)));
+    assert_eq!(1_234.danish_cow(), 1_234.danish_compound_numeral_name());
+    assert_eq!((-7).danish_cow(), "minus syv");
+  }
+
+  #[test]
+  fn spelled_and_grouped_returns_both_forms() {
+    let (spelled, grouped) = 7_023_461.spelled_and_grouped();
+    assert_eq!(spelled, "syv millioner treogtyve tusind fire hundrede og enogtres");
+    assert_eq!(grouped, "7.023.461");
+    assert_eq!((-1_000).spelled_and_grouped(), ("minus et tusind".to_string(), "-1.000".to_string()));
+  }
+
+  #[test]
+  fn magnitude_spelled_has_no_sign() {
+    assert_eq!((-21).magnitude_spelled(), "enogtyve");
+    assert_eq!((-21).magnitude_spelled(), 21.magnitude_spelled());
+    assert!(!i128::MIN.magnitude_spelled().contains(MINUS));
+  }
+
+  #[test]
+  fn leading_decimal_zeros_are_read() {
+    assert_eq!(0.007.danish_compound_numeral_name(), "nul komma nul, nul, syv");
+    assert_eq!(0.7.danish_compound_numeral_name(), "nul komma syv");
+    assert_eq!(0.0.danish_compound_numeral_name(), "nul");
+    let config = DanishConfig::new().trim_trailing_zeros(false);
+    assert_eq!(crate::danish_decimal_string("0.70", &config).unwrap(), "nul komma syv, nul");
+  }
+
+  #[test]
+  fn required_scale_index_is_the_top_group() {
+    assert_eq!(0.required_scale_index(), 0);
+    assert_eq!(999.required_scale_index(), 0);
+    assert_eq!(1_000.required_scale_index(), 1);
+    assert_eq!((-1_000).required_scale_index(), 1);
+    assert_eq!(1_000_000.required_scale_index(), 2);
+    assert_eq!(i128::MAX.required_scale_index(), 12);
+    assert_eq!(i128::MIN.required_scale_index(), 12);
+  }
+
+  #[test]
+  fn whole_decimals_read_two_digits_as_a_number() {
+    let config = DanishConfig::new().decimal_mode(DecimalMode::Whole);
+    assert_eq!(3.45.danish_compound_numeral_name_with(&config), "tre komma femogfyrre");
+    assert_eq!(3.05.danish_compound_numeral_name_with(&config), "tre komma nul fem");
+  }
+
+  #[test]
+  fn whole_decimals_beyond_the_limit_are_read_digit_by_digit() {
+    let config = DanishConfig::new().decimal_mode(DecimalMode::Whole);
+    assert_eq!(3.125.danish_compound_numeral_name_with(&config), "tre komma en, to, fem");
+    assert_eq!(3.0125.danish_compound_numeral_name_with(&config), "tre komma nul, en, to, fem");
+  }
+
+  #[test]
+  fn reversed_groups_are_least_significant_first() {
+    let mut groups = 7_023_461.danish_groups();
+    assert_eq!(7_023_461.danish_groups_reversed(), ["fire hundrede og enogtres", "treogtyve tusind", "syv millioner"]);
+    groups.reverse();
+    assert_eq!(7_023_461.danish_groups_reversed(), groups);
+  }
+
+  #[test]
+  fn split_float_separates_integer_and_decimals() {
+    assert_eq!(split_float(3.25), Ok((3, Some("25".to_string()))));
+    assert_eq!(split_float(-3.25), Ok((-3, Some("25".to_string()))));
+    assert_eq!(split_float(4.0), Ok((4, None)));
+    assert_eq!(split_float(1e20), Ok((100_000_000_000_000_000_000, None)));
+  }
+
+  #[test]
+  fn split_float_rejects_floats_without_a_name() {
+    assert_eq!(split_float(f64::NAN), Err(DanishError::NonFinite));
+    assert_eq!(split_float(f64::INFINITY), Err(DanishError::NonFinite));
+    assert!(matches!(split_float(1e40), Err(DanishError::TooLarge(_))));
+  }
+
+  #[test]
+  fn scale_words_for_multiples_of_three() {
+    assert_eq!(scale_word_for_exponent(3), Some("tusind"));
+    assert_eq!(scale_word_for_exponent(6), Some("million"));
+    assert_eq!(scale_word_for_exponent(9), Some("milliard"));
+    assert_eq!(scale_word_for_exponent(4), None);
+    assert_eq!(scale_word_for_exponent(0), None);
+  }
+
+  #[test]
+  fn teens_take_the_plural_of_high_scales() {
+    assert_eq!(13_000_000.danish_compound_numeral_name(), "tretten millioner");
+    assert_eq!(17_000_000.danish_compound_numeral_name(), "sytten millioner");
+    assert_eq!(11_000_000.danish_compound_numeral_name(), "elleve millioner");
+    assert_eq!(13_000_000_000.danish_compound_numeral_name(), "tretten milliarder");
+  }
+
+  fn assert_len_agrees(number: i128) {
+    assert_eq!(number.danish_len(), number.danish_compound_numeral_name().len(), "{number}");
+  }
+
+  #[test]
+  fn danish_len_agrees_with_the_name_over_sweeps() {
+    for number in -2_000..=2_000 { assert_len_agrees(number); }
+    for scale in 1..=12 {
+      let base = 1000_i128.pow(scale);
+      for offset in -1_001..=1_001 { assert_len_agrees(base + offset); assert_len_agrees(-base - offset); }
+    }
+    assert_len_agrees(i128::MAX);
+    assert_len_agrees(i128::MIN);
+  }
+
+  #[test]
+  fn danish_len_agrees_with_the_name_for_random_numbers() {
+    // A fixed xorshift, so a failure can always be reproduced
+    let mut state: u128 = 0x2545_f491_4f6c_dd1d;
+    for i in 0..20_000 {
+      state ^= state << 13;
+      state ^= state >> 7;
+      state ^= state << 17;
+      // Shift by a varying amount so every magnitude is covered, not just the largest
+      let number = (state >> (i % 127)) as i128;
+      assert_len_agrees(number);
+      assert_len_agrees(number.wrapping_neg());
+    }
+  }
+
+  #[test]
+  fn grouped_digits_follow_the_group_style() {
+    assert_eq!(danish_grouped(1_234_567, GroupStyle::Dot), "1.234.567");
+    assert_eq!(danish_grouped(1_234_567, GroupStyle::ThinSpace), "1\u{202F}234\u{202F}567");
+    assert_eq!(danish_grouped(1_234_567, GroupStyle::None), "1234567");
+    assert_eq!(danish_grouped(-1_234_567, GroupStyle::Dot), "-1.234.567");
+    assert_eq!(danish_grouped(123, GroupStyle::Dot), "123");
+  }
+
+  #[test]
+  fn the_numeral_echo_uses_the_group_style_of_the_config() {
+    let config = DanishConfig::new().include_numeral(true).numeral_grouping(GroupStyle::ThinSpace);
+    assert!(1_234_567.danish_compound_numeral_name_with(&config).ends_with("(1\u{202F}234\u{202F}567)"));
+  }
+
+  #[test]
+  fn pairs_read_decimals_two_at_a_time() {
+    let config = DanishConfig::new().decimal_mode(DecimalMode::Pairs);
+    assert_eq!(0.2576.danish_compound_numeral_name_with(&config), "nul komma femogtyve seksoghalvfjerds");
+    assert_eq!(0.25761.danish_compound_numeral_name_with(&config), "nul komma femogtyve seksoghalvfjerds en");
+    assert_eq!(0.0576.danish_compound_numeral_name_with(&config), "nul komma nul fem seksoghalvfjerds");
+  }
+
+  #[test]
+  fn pairs_are_spelled_according_to_the_config() {
+    let config = DanishConfig::new().decimal_mode(DecimalMode::Pairs).hyphenate_compounds(true);
+    assert_eq!(0.2576.danish_compound_numeral_name_with(&config), "nul komma fem-og-tyve seks-og-halvfjerds");
+  }
+
+  #[test]
+  fn whole_part_floors_towards_negative_infinity() {
+    assert_eq!(3.9.danish_whole_part(), "tre");
+    assert_eq!((-3.1).danish_whole_part(), "minus fire");
+    assert_eq!((-0.5).danish_whole_part(), "minus et");
+    assert_eq!(7.0.danish_whole_part(), "syv");
+  }
+
+  #[test]
+  fn whole_part_reads_floats_without_a_name_like_the_full_name() {
+    assert_eq!(1e40.danish_whole_part(), "et for stort tal");
+    assert_eq!((-1e40).danish_whole_part(), "minus et for stort tal");
+    assert_eq!(f64::NAN.danish_whole_part(), "ikke et tal");
+  }
+}
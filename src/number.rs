@@ -0,0 +1,143 @@
+//
+// IMPORTS
+//
+
+use std::fmt::{self, Alignment, Write};
+
+use crate::{DanishCompoundNumeral, DanishConfig, DanishError, FLOAT_INTEGER_LIMIT};
+
+//
+// DANISH NUMBER
+//
+
+// A number that displays as its Danish compound numeral name
+// println!("{}", DanishNumber::from(21)) -> "enogtyve"
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DanishNumber(Value);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+  Integer(i128),
+  Float(f64)
+}
+
+impl DanishNumber {
+  // Returns the integer the number was made from, or None if it was made from a float
+  pub fn value(&self) -> Option<i128> {
+    match self.0 {
+      Value::Integer(number) => Some(number),
+      Value::Float(..) => None
+    }
+  }
+
+  // Returns the number as a float. Integers beyond 2^53 lose precision
+  pub fn float_value(&self) -> f64 {
+    match self.0 {
+      Value::Integer(number) => number as f64,
+      Value::Float(number) => number
+    }
+  }
+}
+
+impl From<i128> for DanishNumber {
+  fn from(number: i128) -> Self { DanishNumber(Value::Integer(number)) }
+}
+
+// Floats are checked once here, so a DanishNumber always holds a float that can be named
+impl TryFrom<f64> for DanishNumber {
+  type Error = DanishError;
+
+  fn try_from(number: f64) -> Result<Self, Self::Error> {
+    if !number.is_finite() { return Err(DanishError::NonFinite); }
+    if number.abs() >= FLOAT_INTEGER_LIMIT { return Err(DanishError::too_large(&format!("{:.0}", number.trunc()))); }
+    Ok(DanishNumber(Value::Float(number)))
+  }
+}
+
+// Writes a name padded to the width of the formatter, with its fill and alignment like a string. Names are left-aligned by default
+// Unlike Formatter::pad the precision never truncates the name, as for numbers it means decimal places
+pub(crate) fn pad_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+  let padding = f.width().unwrap_or(0).saturating_sub(name.chars().count());
+  let (before, after) = match f.align() {
+    Some(Alignment::Right) => (padding, 0),
+    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+    _ => (0, padding)
+  };
+
+  let fill = f.fill();
+  for _ in 0..before { f.write_char(fill)?; }
+  f.write_str(name)?;
+  for _ in 0..after { f.write_char(fill)?; }
+  Ok(())
+}
+
+impl fmt::Display for DanishNumber {
+  // A precision like "{:.2}" spells float-backed numbers with exactly that many decimals, zeros included - 2.999 -> "tre komma nul, nul"
+  // Integer-backed numbers have no decimals to round, so they ignore it. A width pads the name like a string - "{:>12}"
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let string = match self.0 {
+      Value::Integer(number) => number.danish_compound_numeral_name(),
+      Value::Float(number) => number.danish_compound_numeral_name_with(&DanishConfig::new().decimal_places(f.precision()))
+    };
+
+    pad_name(f, &string)
+  }
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn precision_rounds_floats_before_spelling() {
+    let number = DanishNumber::try_from(3.24159).unwrap();
+    assert_eq!(format!("{number:.1}"), "tre komma to");
+    assert_eq!(format!("{number:.3}"), "tre komma to, fire, to");
+    assert_eq!(format!("{number}"), "tre komma to, fire, en, fem, ni");
+  }
+
+  #[test]
+  fn precision_spells_every_requested_decimal() {
+    assert_eq!(format!("{:.2}", DanishNumber::try_from(2.999).unwrap()), "tre komma nul, nul");
+    assert_eq!(format!("{:.3}", DanishNumber::try_from(1.5).unwrap()), "et komma fem, nul, nul");
+    assert_eq!(format!("{:.0}", DanishNumber::try_from(2.5).unwrap()), "to");
+  }
+
+  #[test]
+  fn precision_is_ignored_for_integers() {
+    assert_eq!(format!("{:.2}", DanishNumber::from(21)), "enogtyve");
+  }
+
+  #[test]
+  fn width_fill_and_alignment_pad_the_name() {
+    assert_eq!(format!("[{:>12}]", DanishNumber::from(5)), "[         fem]");
+    assert_eq!(format!("[{:<6}]", DanishNumber::from(5)), "[fem   ]");
+    assert_eq!(format!("[{:*^7}]", DanishNumber::from(5)), "[**fem**]");
+    assert_eq!(format!("[{:>6}]", DanishNumber::from(101)), "[et hundrede og én]");
+    assert_eq!(format!("[{:>14.1}]", DanishNumber::try_from(2.25).unwrap()), "[   to komma to]");
+  }
+
+  #[test]
+  fn value_returns_the_number_it_was_made_from() {
+    assert_eq!(DanishNumber::from(-42).value(), Some(-42));
+    assert_eq!(DanishNumber::try_from(2.5).unwrap().value(), None);
+    assert_eq!(DanishNumber::try_from(2.5).unwrap().float_value(), 2.5);
+    assert_eq!(DanishNumber::from(7).float_value(), 7.0);
+  }
+
+  #[test]
+  fn try_from_accepts_finite_floats() {
+    assert_eq!(DanishNumber::try_from(2.5).unwrap().to_string(), "to komma fem");
+  }
+
+  #[test]
+  fn try_from_rejects_floats_without_a_name() {
+    assert_eq!(DanishNumber::try_from(f64::NAN), Err(DanishError::NonFinite));
+    assert_eq!(DanishNumber::try_from(f64::INFINITY), Err(DanishError::NonFinite));
+    assert!(matches!(DanishNumber::try_from(1e40), Err(DanishError::TooLarge(_))));
+  }
+}
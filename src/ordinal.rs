@@ -0,0 +1,267 @@
+//
+// IMPORTS
+//
+
+use crate::{Case, DanishCompoundNumeral, DanishError, AND, EMPH_ONE, MINUS, NEUTER_ONE, NUMBER_NAMES, PLURAL_SUFFIX};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+// Ordinal names, laid out like NUMBER_NAMES
+const ORDINAL_NAMES: &[&[&str]] = &[
+  &[
+    "nulte",
+    "første",
+    "anden",
+    "tredje",
+    "fjerde",
+    "femte",
+    "sjette",
+    "syvende",
+    "ottende",
+    "niende"
+  ],
+  &[
+    "tiende",
+    "ellevte",
+    "tolvte",
+    "trettende",
+    "fjortende",
+    "femtende",
+    "sekstende",
+    "syttende",
+    "attende",
+    "nittende"
+  ],
+  &[
+    "tyvende",
+    "tredivte",
+    "fyrretyvende",
+    "halvtredsindstyvende",
+    "tresindstyvende",
+    "halvfjerdsindstyvende",
+    "firsindstyvende",
+    "halvfemsindstyvende"
+  ]
+];
+
+const NEUTER_SECOND: &str = "andet"; // "anden" agrees with a neuter noun - "det andet år"
+const ORDINAL_SUFFIX: &str = "te";       // "million" -> "millionte"
+const ORDINAL_THOUSAND: &str = "tusinde"; // "tusind" -> "tusinde"
+
+const COMMON_ARTICLE: &str = "den";
+const NEUTER_ARTICLE: &str = "det";
+const RANGE_FROM: &str = "fra";
+const RANGE_TO: &str = "til";
+
+const CENTURY: &str = "århundrede";
+
+// The decades of a century, "the twenties" etc.
+const DECADE_NAMES: &[&str] = &[
+  "nullerne", // 2000-2009, and by extension the first decade of any century
+  "tierne",
+  "tyverne",
+  "trediverne",
+  "fyrrerne",
+  "halvtredserne",
+  "tresserne",
+  "halvfjerdserne",
+  "firserne",
+  "halvfemserne"
+];
+
+//
+// ORDINALS
+//
+
+// Returns the ordinal form of a single word of a cardinal name
+fn ordinal_word(word: &str) -> String {
+  // "én", "et" and "en" are all "første"
+  if word == EMPH_ONE || word == NEUTER_ONE { return ORDINAL_NAMES[0][1].to_string(); }
+
+  for (i, names) in NUMBER_NAMES[..3].iter().enumerate() {
+    if let Some(j) = names.iter().position(|name| *name == word) {
+      return ORDINAL_NAMES[i][j].to_string();
+    }
+  }
+
+  // Compounds of ones and tens like "enogtyve" only change their tens - "enogtyvende"
+  for (j, tens) in NUMBER_NAMES[2].iter().enumerate() {
+    if let Some(ones) = word.strip_suffix(tens).and_then(|rest| rest.strip_suffix(AND)) {
+      return format!("{ones}{AND}{}", ORDINAL_NAMES[2][j]);
+    }
+  }
+
+  // Orders of magnitude. "hundrede" is the same as an ordinal
+  if word == NUMBER_NAMES[3][0] { return ORDINAL_THOUSAND.to_string(); }
+  let singular = word.strip_suffix(PLURAL_SUFFIX).unwrap_or(word);
+  if NUMBER_NAMES[3].contains(&singular) { return format!("{singular}{ORDINAL_SUFFIX}"); }
+
+  word.to_string()
+}
+
+// Returns the Danish ordinal name of a number, "tredje" for 3 or "enogtyvende" for 21
+// Only the last word of the compound numeral name changes, and a leading "et" or "en" is left out - "hundrede og første"
+// Zero is "nulte", and negative numbers keep their "minus" in front - -3 -> "minus tredje"
+pub fn danish_ordinal_name(number: i128) -> String {
+  danish_ordinal_name_gendered(number, Gender::Common)
+}
+
+// Same as danish_ordinal_name, but agreeing with a noun of the given gender
+// Only second place agrees, "anden" or "andet", and only on its own - "toogtyvende" is the same for both
+pub fn danish_ordinal_name_gendered(number: i128, gender: Gender) -> String {
+  let cardinal = number.danish_compound_numeral_name();
+  let (rest, last) = match cardinal.rsplit_once(' ') {
+    Some((rest, last)) => (Some(rest), last),
+    None => (None, cardinal.as_str())
+  };
+
+  let mut ordinal = ordinal_word(last);
+  if gender == Gender::Neuter && ordinal == ORDINAL_NAMES[0][2] { ordinal = NEUTER_SECOND.to_string(); }
+
+  let mut words: Vec<&str> = rest.map_or(vec![], |rest| rest.split(' ').collect());
+
+  // Like "den hundrede gang", the one in front of the largest order of magnitude is left out - "hundrede og første"
+  let first = if words.first() == Some(&MINUS) { 1 } else { 0 };
+  if words.get(first).is_some_and(|word| *word == NEUTER_ONE || *word == NUMBER_NAMES[0][1]) { words.remove(first); }
+
+  words.push(&ordinal);
+  words.join(" ")
+}
+
+// The grammatical gender of the noun an ordinal belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gender {
+  #[default]
+  Common, // "den tredje dag"
+  Neuter  // "det tredje år"
+}
+
+impl Gender {
+  // The definite article of the gender
+  pub(crate) fn article(self) -> &'static str {
+    match self {
+      Gender::Common => COMMON_ARTICLE,
+      Gender::Neuter => NEUTER_ARTICLE
+    }
+  }
+}
+
+// Returns the Danish reading of a range of ordinals, 3 to 5 -> "fra den tredje til den femte"
+// The range must not be reversed, so from must be at most to
+pub fn danish_ordinal_range(from: i128, to: i128, gender: Gender) -> Result<String, DanishError> {
+  if from > to { return Err(DanishError::InvalidRange); }
+
+  let article = gender.article();
+  Ok(format!("{RANGE_FROM} {article} {} {RANGE_TO} {article} {}", danish_ordinal_name_gendered(from, gender), danish_ordinal_name_gendered(to, gender)))
+}
+
+// Returns the Danish reading of a regnal name, 4 -> "Christian den Fjerde"
+// Like the numeral it stands for, the ordinal is capitalised. Regnal numbers start at 1
+pub fn danish_regnal(name: &str, number: i128) -> Result<String, DanishError> {
+  if number < 1 { return Err(DanishError::OutOfRange); }
+  Ok(format!("{name} {COMMON_ARTICLE} {}", Case::UpperFirst.apply(danish_ordinal_name(number))))
+}
+
+//
+// CENTURIES AND DECADES
+//
+
+// Returns the name of the n'th century, "det nittende århundrede" for 19 (the 1800s)
+// "århundrede" is neuter, so the second century is "det andet århundrede". Centuries are counted from 1
+pub fn danish_century(n: i128) -> Result<String, DanishError> {
+  if n < 1 { return Err(DanishError::OutOfRange); }
+  Ok(format!("{NEUTER_ARTICLE} {} {CENTURY}", danish_ordinal_name_gendered(n, Gender::Neuter)))
+}
+
+// Returns the colloquial name of the decade a year lies in, "trediverne" for 1934
+// The century is left out, so the first decade of any century is "nullerne" like 2000-2009
+pub fn danish_decade(year: i128) -> String {
+  DECADE_NAMES[(year.unsigned_abs() % 100 / 10) as usize].to_string()
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn centuries_are_neuter_ordinals() {
+    assert_eq!(danish_century(19).unwrap(), "det nittende århundrede");
+    assert_eq!(danish_century(21).unwrap(), "det enogtyvende århundrede");
+    assert_eq!(danish_century(1).unwrap(), "det første århundrede");
+    assert_eq!(danish_century(2).unwrap(), "det andet århundrede");
+  }
+
+  #[test]
+  fn centuries_start_at_one() {
+    assert_eq!(danish_century(0), Err(DanishError::OutOfRange));
+    assert_eq!(danish_century(-5), Err(DanishError::OutOfRange));
+  }
+
+  #[test]
+  fn decades_drop_the_century() {
+    assert_eq!(danish_decade(1934), "trediverne");
+    assert_eq!(danish_decade(1989), "firserne");
+    assert_eq!(danish_decade(2005), "nullerne");
+    assert_eq!(danish_decade(1905), "nullerne");
+    assert_eq!(danish_decade(1915), "tierne");
+  }
+
+  #[test]
+  fn ordinal_ranges_agree_with_the_gender() {
+    assert_eq!(danish_ordinal_range(3, 5, Gender::Common).unwrap(), "fra den tredje til den femte");
+    assert_eq!(danish_ordinal_range(1, 2, Gender::Neuter).unwrap(), "fra det første til det andet");
+  }
+
+  #[test]
+  fn reversed_ordinal_ranges_are_errors() {
+    assert_eq!(danish_ordinal_range(5, 3, Gender::Common), Err(DanishError::InvalidRange));
+  }
+
+  #[test]
+  fn second_agrees_with_the_gender_only_on_its_own() {
+    assert_eq!(format!("den {}", danish_ordinal_name_gendered(2, Gender::Common)), "den anden");
+    assert_eq!(format!("det {}", danish_ordinal_name_gendered(2, Gender::Neuter)), "det andet");
+    assert_eq!(danish_ordinal_name_gendered(22, Gender::Neuter), "toogtyvende");
+    assert_eq!(danish_ordinal_name_gendered(102, Gender::Neuter), "hundrede og andet");
+    assert_eq!(danish_ordinal_name(2), "anden");
+  }
+
+  #[test]
+  fn regnal_numbers_read_the_capitalised_ordinal() {
+    assert_eq!(danish_regnal("Christian", 4).unwrap(), "Christian den Fjerde");
+    assert_eq!(danish_regnal("Frederik", 10).unwrap(), "Frederik den Tiende");
+    assert_eq!(danish_regnal("Konge", 21).unwrap(), "Konge den Enogtyvende");
+    assert_eq!(danish_regnal("Margrete", 0), Err(DanishError::OutOfRange));
+  }
+
+  #[test]
+  fn zero_and_negative_ordinals_do_not_panic() {
+    assert_eq!(danish_ordinal_name(0), "nulte");
+    assert_eq!(danish_ordinal_name(-3), "minus tredje");
+    assert_eq!(danish_ordinal_name(-21), "minus enogtyvende");
+    assert!(danish_ordinal_name(i128::MIN).ends_with("otteogtyvende"));
+  }
+
+  #[test]
+  fn ordinals_from_a_hundred_up_leave_out_the_one() {
+    assert_eq!(danish_ordinal_name(100), "hundrede");
+    assert_eq!(danish_ordinal_name(101), "hundrede og første");
+    assert_eq!(danish_ordinal_name(1_000_000), "millionte");
+    assert_eq!(danish_ordinal_name(-100), "minus hundrede");
+    assert_eq!(danish_ordinal_name(200), "to hundrede");
+  }
+
+  #[test]
+  fn centuries_and_regnal_numbers_from_a_hundred_up() {
+    assert_eq!(danish_century(100).unwrap(), "det hundrede århundrede");
+    assert_eq!(danish_century(101).unwrap(), "det hundrede og første århundrede");
+    assert_eq!(danish_regnal("X", 100).unwrap(), "X den Hundrede");
+    assert_eq!(danish_regnal("X", 101).unwrap(), "X den Hundrede og første");
+  }
+}
@@ -0,0 +1,418 @@
+//
+// IMPORTS
+//
+
+use std::{fmt, ops::Range, str::FromStr};
+
+use crate::{scale_table, DanishNumber, DecimalMode, AND, DECIMAL_SEPARATOR, EMPH_NEUTER_ONE, EMPH_ONE, HUNDRED, MINUS, NEUTER_ONE, NUMBER_NAMES, PLURAL_SUFFIX, WHOLE_DECIMALS_LIMIT};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+// Other spellings the parser accepts besides the ones the formatter uses
+const HUNDRED_ELIDED: &str = "hundred";
+const THOUSAND_DEFINITE: &str = "tusinde";
+
+//
+// ERRORS
+//
+
+// What went wrong when parsing a Danish compound numeral name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+  Empty,          // There were no words at all
+  UnknownWord,    // A word that is not part of any number
+  UnexpectedWord, // A number word in a place it cannot be, like the second "fem" in "fem fem"
+  OutOfRange      // The number does not fit in an i128
+}
+
+// The error of parsing a Danish compound numeral name, with the byte offset of the offending word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanishParseError {
+  pub offset: usize,
+  pub token: String,
+  pub kind: ParseErrorKind
+}
+
+impl fmt::Display for DanishParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.kind {
+      ParseErrorKind::Empty => write!(f, "Expected a number"),
+      ParseErrorKind::UnknownWord => write!(f, "Unknown word '{}' at byte {}", self.token, self.offset),
+      ParseErrorKind::UnexpectedWord => write!(f, "Unexpected word '{}' at byte {}", self.token, self.offset),
+      ParseErrorKind::OutOfRange => write!(f, "Number is too large at '{}' at byte {}", self.token, self.offset)
+    }
+  }
+}
+
+impl std::error::Error for DanishParseError {}
+
+//
+// PARSING
+//
+
+// The meaning of a single word of a Danish compound numeral name
+pub(crate) enum Word {
+  Zero,
+  Value(i128),  // 1 to 99
+  Hundred,
+  Scale(u32),   // An order of magnitude, by its power of ten
+  And
+}
+
+// Returns the words of a string along with their byte offsets
+pub(crate) fn words(s: &str) -> impl Iterator<Item = (usize, &str)> {
+  s.split(char::is_whitespace)
+    .filter(|word| !word.is_empty())
+    .map(move |word| (word.as_ptr() as usize - s.as_ptr() as usize, word))
+}
+
+// Returns the meaning of a word, or None if it is not part of a number
+pub(crate) fn word_meaning(word: &str) -> Option<Word> {
+  // Hyphenated compounds and the commas between groups are spelled by this crate too
+  let word = word.trim_end_matches(',').replace('-', "").to_lowercase();
+
+  if word == NUMBER_NAMES[0][0] { return Some(Word::Zero); }
+  if word == AND { return Some(Word::And); }
+  if word == HUNDRED || word == HUNDRED_ELIDED { return Some(Word::Hundred); }
+  if word == EMPH_ONE || word == NEUTER_ONE || word == EMPH_NEUTER_ONE { return Some(Word::Value(1)); }
+  if word == THOUSAND_DEFINITE { return Some(Word::Scale(3)); }
+
+  // Ones, teens and tens, straight from the lists
+  for (i, names) in NUMBER_NAMES[..3].iter().enumerate() {
+    if let Some(j) = names.iter().position(|name| *name == word) {
+      return Some(Word::Value(match i { 0 => j as i128, 1 => 10 + j as i128, _ => 20 + 10 * j as i128 }));
+    }
+  }
+
+  // Compounds of ones and tens like "enogtyve"
+  for (j, tens) in NUMBER_NAMES[2].iter().enumerate() {
+    if let Some(ones) = word.strip_suffix(tens).and_then(|rest| rest.strip_suffix(AND)) {
+      if let Some(ones) = NUMBER_NAMES[0][1..].iter().position(|name| *name == ones) {
+        return Some(Word::Value(20 + 10 * j as i128 + ones as i128 + 1));
+      }
+    }
+  }
+
+  // Orders of magnitude, in singular or plural
+  let singular = word.strip_suffix(PLURAL_SUFFIX).unwrap_or(&word);
+  scale_table().iter()
+    .find(|(scale, _)| *scale == word || *scale == singular)
+    .map(|(_, exponent)| Word::Scale(*exponent))
+}
+
+// Returns the meanings of a word, splitting up words written together with "hundrede" and the orders of magnitude
+// "ethundrede" -> [1, hundred] and "treogtyvetusinde" -> [23, thousand]
+fn word_meanings(word: &str) -> Option<Vec<Word>> {
+  if let Some(meaning) = word_meaning(word) { return Some(vec![meaning]); }
+
+  // Try every split into a known start and a multiplier at the end, shortest multiplier first
+  word.char_indices().rev().filter(|(split, _)| *split > 0).find_map(|(split, _)| {
+    let multiplier = word_meaning(&word[split..]).filter(|meaning| matches!(meaning, Word::Hundred | Word::Scale(..)))?;
+    let mut meanings = word_meanings(&word[..split])?;
+    meanings.push(multiplier);
+    Some(meanings)
+  })
+}
+
+// Returns the magnitude named by the words of a Danish compound numeral name, which must not be empty
+fn magnitude(words: &[(usize, &str)]) -> Result<u128, DanishParseError> {
+  let error = |offset: usize, token: &str, kind| DanishParseError { offset, token: token.to_string(), kind };
+
+  // Every word with its meanings, each meaning keeping the offset of the word it came from
+  let mut meanings = vec![];
+  for (offset, word) in words {
+    let word_meanings = word_meanings(word).ok_or_else(|| error(*offset, word, ParseErrorKind::UnknownWord))?;
+    meanings.extend(word_meanings.into_iter().map(|meaning| (*offset, *word, meaning)));
+  }
+
+  let mut total: u128 = 0;       // Everything named by orders of magnitude so far
+  let mut group: u128 = 0;       // The current thousands' group
+  let mut ones: u128 = 0;        // The ones and tens of the current group
+  let mut last_scale = u32::MAX; // Orders of magnitude must come in descending order
+
+  for (i, (offset, word, meaning)) in meanings.iter().enumerate() {
+    let unexpected = || error(*offset, word, ParseErrorKind::UnexpectedWord);
+    match meaning {
+      // Zero is only ever a number of its own
+      Word::Zero => if meanings.len() > 1 { return Err(unexpected()); },
+      Word::Value(value) => {
+        if ones > 0 { return Err(unexpected()); }
+        ones = *value as u128;
+      }
+      Word::Hundred => {
+        if group > 0 || ones >= 10 { return Err(unexpected()); }
+        group = 100 * ones.max(1);
+        ones = 0;
+      }
+      Word::Scale(exponent) => {
+        if *exponent >= last_scale { return Err(unexpected()); }
+        let count = (group + ones).max(1);
+        total = 10_u128.checked_pow(*exponent)
+          .and_then(|scale| scale.checked_mul(count))
+          .and_then(|value| value.checked_add(total))
+          .ok_or_else(|| error(*offset, word, ParseErrorKind::OutOfRange))?;
+        group = 0;
+        ones = 0;
+        last_scale = *exponent;
+      }
+      // "og" only ever joins two parts of a number
+      Word::And => if i == 0 || i == meanings.len() - 1 { return Err(unexpected()); }
+    }
+  }
+
+  // The group is below 1000, but the orders of magnitude can leave less than that below u128::MAX
+  total.checked_add(group + ones).ok_or_else(|| {
+    let (offset, word) = words[words.len() - 1];
+    error(offset, word, ParseErrorKind::OutOfRange)
+  })
+}
+
+// Returns the number a Danish compound numeral name names, "syv millioner treogtyve tusind" -> 7_023_000
+// A leading "minus" makes the number negative, and "minus nul" is just 0
+// The parser accepts the spellings the formatter can produce, and is otherwise strict about word order
+pub fn from_danish(s: &str) -> Result<i128, DanishParseError> {
+  let error = |offset: usize, token: &str, kind| DanishParseError { offset, token: token.to_string(), kind };
+
+  let words: Vec<(usize, &str)> = words(s).collect();
+  let (negative, number) = match words.split_first() {
+    None => return Err(error(s.len(), "", ParseErrorKind::Empty)),
+    Some(((offset, word), [])) if word.eq_ignore_ascii_case(MINUS) => return Err(error(*offset, word, ParseErrorKind::UnexpectedWord)),
+    Some(((_, word), rest)) if word.eq_ignore_ascii_case(MINUS) => (true, rest),
+    Some(..) => (false, &words[..])
+  };
+
+  let magnitude = magnitude(number)?;
+  let value = if negative { 0_i128.checked_sub_unsigned(magnitude) } else { i128::try_from(magnitude).ok() };
+  value.ok_or_else(|| {
+    let (offset, word) = words[words.len() - 1];
+    error(offset, word, ParseErrorKind::OutOfRange)
+  })
+}
+
+// Returns the digit a single word of the decimals names, like "fem" or "en"
+fn decimal_digit(word: &str) -> Option<u128> {
+  match word_meaning(word)? {
+    Word::Zero => Some(0),
+    Word::Value(value) if value < 10 => Some(value as u128),
+    _ => None
+  }
+}
+
+// Returns the number a Danish name with decimals names, "tre komma en, fem" -> 3.15
+// Names without "komma" are read like from_danish
+// With DecimalMode::Digits each decimal must be a single digit like the formatter reads them, so "komma femogtyve" is an error
+// With DecimalMode::Whole the decimals are read as one number below 100 after any leading "nul"s - "komma nul fem" -> 0.05
+// Like the formatter reads them, more than WHOLE_DECIMALS_LIMIT decimal words that are all digits are read digit by digit
+// With DecimalMode::Pairs each word is two decimals, a "nul" before a digit is a pair too, and only the last may be one decimal
+pub fn from_danish_f64(s: &str, mode: DecimalMode) -> Result<f64, DanishParseError> {
+  let error = |offset: usize, token: &str, kind| DanishParseError { offset, token: token.to_string(), kind };
+
+  let words: Vec<(usize, &str)> = words(s).collect();
+  let (negative, number) = match words.split_first() {
+    None => return Err(error(s.len(), "", ParseErrorKind::Empty)),
+    Some(((offset, word), [])) if word.eq_ignore_ascii_case(MINUS) => return Err(error(*offset, word, ParseErrorKind::UnexpectedWord)),
+    Some(((_, word), rest)) if word.eq_ignore_ascii_case(MINUS) => (true, rest),
+    Some(..) => (false, &words[..])
+  };
+
+  let Some(separator) = number.iter().position(|(_, word)| word.eq_ignore_ascii_case(DECIMAL_SEPARATOR)) else {
+    return from_danish(s).map(|number| number as f64);
+  };
+  let (integer, decimals) = (&number[..separator], &number[separator + 1..]);
+  let (offset, word) = number[separator];
+  if integer.is_empty() || decimals.is_empty() { return Err(error(offset, word, ParseErrorKind::UnexpectedWord)); }
+
+  let integer = magnitude(integer)?;
+  let long_digits = decimals.len() > WHOLE_DECIMALS_LIMIT && decimals.iter().all(|(_, word)| decimal_digit(word).is_some());
+  let mode = if mode == DecimalMode::Whole && long_digits { DecimalMode::Digits } else { mode };
+  let decimals = match mode {
+    DecimalMode::Digits => decimals.iter()
+      .map(|(offset, word)| decimal_digit(word).map(|digit| digit.to_string()).ok_or_else(|| error(*offset, word, ParseErrorKind::UnexpectedWord)))
+      .collect::<Result<String, DanishParseError>>()?,
+    DecimalMode::Pairs => {
+      let mut digits = String::new();
+      let mut i = 0;
+      while i < decimals.len() {
+        let (offset, word) = decimals[i];
+        let unexpected = || error(offset, word, ParseErrorKind::UnexpectedWord);
+        let next = decimals.get(i + 1).and_then(|(_, next)| decimal_digit(next));
+        match word_meaning(word) {
+          // "nul fem" is the pair "05". A "nul" with no digit after it is a last decimal of its own
+          Some(Word::Zero) => match next {
+            Some(digit) => { digits.push_str(&format!("0{digit}")); i += 1; }
+            None if i == decimals.len() - 1 => digits.push('0'),
+            None => return Err(unexpected())
+          },
+          Some(Word::Value(value)) if value >= 10 => digits.push_str(&value.to_string()),
+          Some(Word::Value(value)) if i == decimals.len() - 1 => digits.push_str(&value.to_string()),
+          _ => return Err(unexpected())
+        }
+        i += 1;
+      }
+      digits
+    }
+    DecimalMode::Whole => {
+      // Like the formatter writes them, the decimals after the zeros are a single word below 100, so "komma tusind" is an error
+      let zeros = decimals.iter().take_while(|(_, word)| decimal_digit(word) == Some(0)).count();
+      let rest = match &decimals[zeros..] {
+        [] => String::new(),
+        [(offset, word)] => match word_meaning(word) {
+          Some(Word::Value(value)) => value.to_string(),
+          _ => return Err(error(*offset, word, ParseErrorKind::UnexpectedWord))
+        },
+        [_, (offset, word), ..] => return Err(error(*offset, word, ParseErrorKind::UnexpectedWord))
+      };
+      format!("{}{rest}", "0".repeat(zeros))
+    }
+  };
+
+  // The float is made from its decimal string, so it is as close to the name as a float can be
+  let number = format!("{}{integer}.{decimals}", if negative { "-" } else { "" });
+  number.parse::<f64>().map_err(|_| error(offset, word, ParseErrorKind::OutOfRange))
+}
+
+impl FromStr for DanishNumber {
+  type Err = DanishParseError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_danish(s).map(DanishNumber::from)
+  }
+}
+
+//
+// NUMBERS IN TEXT
+//
+
+// Returns every Danish compound numeral name in a text with its byte range and value, the reverse of humanize_text
+// "Vi var tre hundrede og fem, nu er vi to" -> (7..26, 305) and (37..39, 2)
+// The longest run of words that names a number wins. Like any number word, the articles "en" and "et" are read as 1
+pub fn danish_number_tokens(text: &str) -> impl Iterator<Item = (Range<usize>, i128)> + '_ {
+  // Punctuation around a word is not part of the number, like the full stop after "fem."
+  let words: Vec<(usize, &str)> = words(text)
+    .map(|(offset, word)| {
+      let trimmed = word.trim_start_matches(|c: char| c.is_ascii_punctuation());
+      (offset + word.len() - trimmed.len(), trimmed.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+    })
+    .filter(|(_, word)| !word.is_empty())
+    .collect();
+
+  let mut i = 0;
+  std::iter::from_fn(move || {
+    while i < words.len() {
+      // The run of words that could be part of a number, with "minus" only in front
+      let run = (i..words.len())
+        .find(|&j| word_meanings(words[j].1).is_none() && !(j == i && words[j].1.eq_ignore_ascii_case(MINUS)))
+        .unwrap_or(words.len());
+
+      // Shorten the run from the end until it names a number, as in "tre og fire" where only "tre" does
+      for end in (i + 1..=run).rev() {
+        let phrase = words[i..end].iter().map(|(_, word)| *word).collect::<Vec<&str>>().join(" ");
+        if let Ok(value) = from_danish(&phrase) {
+          let (start, _) = words[i];
+          let (last, word) = words[end - 1];
+          i = end;
+          return Some((start..last + word.len(), value));
+        }
+      }
+      i += 1;
+    }
+    None
+  })
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_danish_reads_names() {
+    assert_eq!(from_danish("syvogtyve"), Ok(27));
+    assert_eq!(from_danish("et hundrede og én"), Ok(101));
+    assert_eq!(from_danish("enogtyve tusind"), Ok(21_000));
+    assert_eq!(from_danish("minus tre"), Ok(-3));
+  }
+
+  #[test]
+  fn errors_report_the_offset_of_the_bad_token() {
+    let error = from_danish("to hundrede fisk tre").unwrap_err();
+    assert_eq!(error, DanishParseError { offset: 12, token: "fisk".to_string(), kind: ParseErrorKind::UnknownWord });
+    assert_eq!(&"to hundrede fisk tre"[error.offset..error.offset + error.token.len()], "fisk");
+
+    let error = from_danish("fem fem").unwrap_err();
+    assert_eq!((error.offset, error.kind), (4, ParseErrorKind::UnexpectedWord));
+    assert_eq!(from_danish("").unwrap_err().kind, ParseErrorKind::Empty);
+  }
+
+  #[test]
+  fn from_danish_reads_a_leading_minus() {
+    assert_eq!(from_danish("minus fem"), Ok(-5));
+    assert_eq!(from_danish("minus et hundrede"), Ok(-100));
+    assert_eq!(from_danish("minus ethundrede"), Ok(-100));
+    assert_eq!(from_danish("minus nul"), Ok(0));
+    assert_eq!(from_danish("minus").unwrap_err().kind, ParseErrorKind::UnexpectedWord);
+    assert_eq!(from_danish("plus fem").unwrap_err().kind, ParseErrorKind::UnknownWord);
+  }
+
+  #[test]
+  fn from_danish_f64_reads_digit_decimals() {
+    assert_eq!(from_danish_f64("tre komma en fem", DecimalMode::Digits), Ok(3.15));
+    assert_eq!(from_danish_f64("nul komma nul en", DecimalMode::Digits), Ok(0.01));
+    assert_eq!(from_danish_f64("minus to komma fem", DecimalMode::Digits), Ok(-2.5));
+  }
+
+  #[test]
+  fn from_danish_f64_needs_a_mode_for_grouped_decimals() {
+    assert_eq!(from_danish_f64("tre komma femogtyve", DecimalMode::Digits).unwrap_err().kind, ParseErrorKind::UnexpectedWord);
+    assert_eq!(from_danish_f64("tre komma femogtyve", DecimalMode::Whole), Ok(3.25));
+  }
+
+  #[test]
+  fn from_danish_f64_reads_long_whole_decimals_digit_by_digit() {
+    assert_eq!(from_danish_f64("tre komma femogfyrre", DecimalMode::Whole), Ok(3.45));
+    assert_eq!(from_danish_f64("tre komma nul fem", DecimalMode::Whole), Ok(3.05));
+    assert_eq!(from_danish_f64("tre komma en, to, fem", DecimalMode::Whole), Ok(3.125));
+  }
+
+  #[test]
+  fn from_danish_f64_reads_whole_decimals_below_a_hundred_only() {
+    assert_eq!(from_danish_f64("tre komma tusind", DecimalMode::Whole).unwrap_err().kind, ParseErrorKind::UnexpectedWord);
+    let error = from_danish_f64("tre komma et hundrede og fem", DecimalMode::Whole).unwrap_err();
+    assert_eq!((error.token.as_str(), error.kind), ("hundrede", ParseErrorKind::UnexpectedWord));
+  }
+
+  #[test]
+  fn number_tokens_find_the_spelled_numbers_of_a_text() {
+    let text = "Jeg har tre katte og femogtyve høns.";
+    let tokens: Vec<_> = danish_number_tokens(text).collect();
+    assert_eq!(tokens, [(8..11, 3), (21..30, 25)]);
+  }
+
+  #[test]
+  fn number_tokens_split_runs_the_parser_rejects() {
+    let text = "tre og fire, et hundrede og en!";
+    let tokens: Vec<_> = danish_number_tokens(text).map(|(range, value)| (&text[range], value)).collect();
+    assert_eq!(tokens, [("tre", 3), ("fire", 4), ("et hundrede og en", 101)]);
+  }
+
+  #[test]
+  fn magnitudes_past_u128_are_out_of_range() {
+    // The largest multiple of a thousand below u128::MAX, which leaves only 455 below it
+    let thousands = "tre hundrede og fyrre sekstillioner to hundrede og toogfirs kvintilliarder tre hundrede og seksogtres kvintillioner \
+      ni hundrede og tyve kvadrilliarder ni hundrede og otteogtredive kvadrillioner fire hundrede og treogtres trilliarder \
+      fire hundrede og treogtres trillioner tre hundrede og fireoghalvfjerds billiarder seks hundrede og syv billioner \
+      fire hundrede og enogtredive milliarder syv hundrede og otteogtres millioner to hundrede og elleve tusind";
+    let words: Vec<_> = words(thousands).collect();
+    assert_eq!(magnitude(&words), Ok(340_282_366_920_938_463_463_374_607_431_768_211_000));
+
+    let name = format!("{thousands} ni hundrede og nioghalvfems");
+    assert_eq!(from_danish(&name).unwrap_err().kind, ParseErrorKind::OutOfRange);
+    // The tokens fall back to shorter runs that fit, and none of them is the whole name
+    assert!(danish_number_tokens(&name).all(|(range, _)| range != (0..name.len())));
+  }
+}
@@ -0,0 +1,112 @@
+//
+// IMPORTS
+//
+
+use std::cmp::Ordering;
+
+use crate::{fraction::gcd, DanishCompoundNumeral, NUMBER_NAMES};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+const AGAINST: &str = "mod";
+const TO: &str = "til"; // Ratios and odds, "to til en"
+const IS: &str = "er";
+const GREATER: &str = "større end";
+const LESS: &str = "mindre end";
+const EQUAL: &str = "lig med";
+
+//
+// SCORES
+//
+
+// How a score is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoreStyle {
+  #[default]
+  Hyphenated, // 3-1 -> "tre-et"
+  Against     // 3-1 -> "tre mod et"
+}
+
+// Returns the Danish reading of a sports result, 3-1 -> "tre-et"
+// A score of one is read "et" like the plain number, as is usual for results - "et-nul"
+pub fn danish_score(a: i128, b: i128, style: ScoreStyle) -> String {
+  let (a, b) = (a.danish_compound_numeral_name(), b.danish_compound_numeral_name());
+  match style {
+    ScoreStyle::Hyphenated => format!("{a}-{b}"),
+    ScoreStyle::Against => format!("{a} {AGAINST} {b}")
+  }
+}
+
+//
+// RATIOS
+//
+
+// Returns the Danish name of a term of a ratio, where one is the common gender "en"
+fn ratio_term(term: i128) -> String {
+  if term == 1 { NUMBER_NAMES[0][1].to_string() } else { term.danish_compound_numeral_name() }
+}
+
+// Returns the Danish reading of a ratio or odds, 2:1 -> "to til en"
+// With simplify the ratio is reduced first, 4:2 -> "to til en". A ratio with a zero term cannot be reduced and is read as it is
+pub fn danish_ratio(a: i128, b: i128, simplify: bool) -> String {
+  let divisor = if simplify && a != 0 && b != 0 { gcd(a.unsigned_abs(), b.unsigned_abs()) as i128 } else { 1 };
+  format!("{} {TO} {}", ratio_term(a / divisor), ratio_term(b / divisor))
+}
+
+//
+// COMPARISONS
+//
+
+// Returns how two numbers compare, with both of them named - 7 and 5 -> "syv er større end fem"
+// Equal numbers read "lig med", 5 and 5 -> "fem er lig med fem"
+pub fn danish_comparison(a: i128, b: i128) -> String {
+  let relation = match a.cmp(&b) {
+    Ordering::Greater => GREATER,
+    Ordering::Less => LESS,
+    Ordering::Equal => EQUAL
+  };
+  format!("{} {IS} {relation} {}", a.danish_compound_numeral_name(), b.danish_compound_numeral_name())
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn scores_read_in_both_styles() {
+    assert_eq!(danish_score(3, 1, ScoreStyle::Hyphenated), "tre-et");
+    assert_eq!(danish_score(3, 1, ScoreStyle::Against), "tre mod et");
+    assert_eq!(danish_score(1, 21, ScoreStyle::Against), "et mod enogtyve");
+  }
+
+  #[test]
+  fn draws_read_the_same_number_twice() {
+    assert_eq!(danish_score(0, 0, ScoreStyle::Hyphenated), "nul-nul");
+    assert_eq!(danish_score(0, 0, ScoreStyle::Against), "nul mod nul");
+  }
+
+  #[test]
+  fn ratios_read_a_til_b() {
+    assert_eq!(danish_ratio(2, 1, false), "to til en");
+    assert_eq!(danish_ratio(1, 3, false), "en til tre");
+  }
+
+  #[test]
+  fn ratios_can_be_simplified() {
+    assert_eq!(danish_ratio(4, 2, true), "to til en");
+    assert_eq!(danish_ratio(4, 2, false), "fire til to");
+  }
+
+  #[test]
+  fn comparisons_spell_the_relation() {
+    assert_eq!(danish_comparison(7, 5), "syv er større end fem");
+    assert_eq!(danish_comparison(5, 7), "fem er mindre end syv");
+    assert_eq!(danish_comparison(-3, -3), "minus tre er lig med minus tre");
+  }
+}
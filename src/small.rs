@@ -0,0 +1,63 @@
+//
+// DANISH LANGUAGE STRINGS
+//
+
+// The names of 0 to 99 as danish_compound_numeral_name spells them, written out so they can be used at compile time
+const SMALL_NAMES: [&str; 100] = [
+  "nul", "et", "to", "tre", "fire", "fem", "seks", "syv", "otte", "ni",
+  "ti", "elleve", "tolv", "tretten", "fjorten", "femten", "seksten", "sytten", "atten", "nitten",
+  "tyve", "enogtyve", "toogtyve", "treogtyve", "fireogtyve", "femogtyve", "seksogtyve", "syvogtyve", "otteogtyve", "niogtyve",
+  "tredive", "enogtredive", "toogtredive", "treogtredive", "fireogtredive", "femogtredive", "seksogtredive", "syvogtredive", "otteogtredive", "niogtredive",
+  "fyrre", "enogfyrre", "toogfyrre", "treogfyrre", "fireogfyrre", "femogfyrre", "seksogfyrre", "syvogfyrre", "otteogfyrre", "niogfyrre",
+  "halvtreds", "enoghalvtreds", "tooghalvtreds", "treoghalvtreds", "fireoghalvtreds", "femoghalvtreds", "seksoghalvtreds", "syvoghalvtreds", "otteoghalvtreds", "nioghalvtreds",
+  "tres", "enogtres", "toogtres", "treogtres", "fireogtres", "femogtres", "seksogtres", "syvogtres", "otteogtres", "niogtres",
+  "halvfjerds", "enoghalvfjerds", "tooghalvfjerds", "treoghalvfjerds", "fireoghalvfjerds", "femoghalvfjerds", "seksoghalvfjerds", "syvoghalvfjerds", "otteoghalvfjerds", "nioghalvfjerds",
+  "firs", "enogfirs", "toogfirs", "treogfirs", "fireogfirs", "femogfirs", "seksogfirs", "syvogfirs", "otteogfirs", "niogfirs",
+  "halvfems", "enoghalvfems", "tooghalvfems", "treoghalvfems", "fireoghalvfems", "femoghalvfems", "seksoghalvfems", "syvoghalvfems", "otteoghalvfems", "nioghalvfems"
+];
+
+//
+// COMPILE TIME NAMES
+//
+
+// Returns the Danish name of a number from 0 to 99, usable in const contexts
+// const LABEL: &str = danish_small(42); -> "toogfyrre"
+// Panics (or fails to compile in a const) for numbers above 99
+pub const fn danish_small(n: u8) -> &'static str {
+  assert!(n < 100, "danish_small only names numbers from 0 to 99");
+  SMALL_NAMES[n as usize]
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::DanishCompoundNumeral;
+
+  const ZERO: &str = danish_small(0);
+  const SEVENTEEN: &str = danish_small(17);
+  const FORTY_TWO: &str = danish_small(42);
+  const NINETY_NINE: &str = danish_small(99);
+
+  #[test]
+  fn small_names_resolve_at_compile_time() {
+    assert_eq!(ZERO, "nul");
+    assert_eq!(SEVENTEEN, "sytten");
+    assert_eq!(FORTY_TWO, "toogfyrre");
+    assert_eq!(NINETY_NINE, "nioghalvfems");
+  }
+
+  #[test]
+  fn small_names_match_the_runtime_names() {
+    for n in 0..100 { assert_eq!(danish_small(n), i128::from(n).danish_compound_numeral_name()); }
+  }
+
+  #[test]
+  #[should_panic]
+  fn numbers_above_99_panic() {
+    danish_small(100);
+  }
+}
@@ -0,0 +1,227 @@
+//
+// IMPORTS
+//
+
+use crate::{danish_decimal_string, danish_ordinal_name, danish_ordinal_name_gendered, DanishCompoundNumeral, DanishConfig, Gender, MINUS};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+// The genders whose definite articles make "N." an ordinal - "den 3." is "den tredje" and "det 2." is "det andet"
+const ORDINAL_GENDERS: &[Gender] = &[Gender::Common, Gender::Neuter];
+
+const PLUS: &str = "plus";
+
+//
+// HUMANIZE TEXT
+//
+
+// Returns the gender of the definite article the text ends with as a word of its own followed by a space, if any
+fn ordinal_article_gender(text: &str) -> Option<Gender> {
+  let text = text.strip_suffix(' ')?;
+  ORDINAL_GENDERS.iter().copied().find(|gender| {
+    let article = gender.article();
+    let start = text.len().saturating_sub(article.len());
+    text.get(start..).is_some_and(|word| word.eq_ignore_ascii_case(article))
+      && !text[..start].chars().next_back().is_some_and(char::is_alphanumeric)
+  })
+}
+
+// Returns the sign attached to the end of the text, if it is not glued to a word or number itself
+// Both the hyphen-minus and the Unicode minus sign U+2212 are read as minus
+fn trailing_sign(text: &str) -> Option<(char, &'static str)> {
+  let mut chars = text.chars().rev();
+  let sign = match chars.next()? {
+    '+' => ('+', PLUS),
+    c @ ('-' | '\u{2212}') => (c, MINUS),
+    _ => return None
+  };
+  if chars.next().is_some_and(char::is_alphanumeric) { None } else { Some(sign) }
+}
+
+// A number at the start of some text
+enum Token {
+  Integer(String), // The digits, without any grouping dots
+  Decimal(String)  // The number with a decimal point, like "3.5"
+}
+
+// Returns the number of digits at the start of the text
+fn digits_len(text: &str) -> usize {
+  text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len())
+}
+
+// Returns the length of a date like "1.1.2020" or "24.12." at the start of the text, and its day, month and year if any
+// The day and month must be one or two digits and the year four, and the date must not be glued to a word
+// Only the shape is checked, so "32.1.2020" is a date too
+fn scan_date(text: &str) -> Option<(usize, u32, u32, Option<u32>)> {
+  let day_len = digits_len(text);
+  let month_start = day_len + 1;
+  if !(1..=2).contains(&day_len) || !text[day_len..].starts_with('.') { return None; }
+  let month_len = digits_len(&text[month_start..]);
+  let year_start = month_start + month_len + 1;
+  if !(1..=2).contains(&month_len) || !text[month_start + month_len..].starts_with('.') { return None; }
+
+  let day = text[..day_len].parse::<u32>().ok()?;
+  let month = text[month_start..month_start + month_len].parse::<u32>().ok()?;
+  let (len, year) = match digits_len(&text[year_start..]) {
+    4 => (year_start + 4, text[year_start..year_start + 4].parse::<u32>().ok()),
+    0 => (year_start, None),
+    _ => return None
+  };
+  if text[len..].chars().next().is_some_and(char::is_alphanumeric) { return None; }
+  Some((len, day, month, year))
+}
+
+// Returns the length of the number at the start of the text, and the number itself
+// Without decimal_point only a comma starts decimals, as a dot after an ordinal article is the ordinal's own
+fn scan_number(text: &str, decimal_point: bool) -> (usize, Token) {
+  let mut end = digits_len(text);
+  let mut integer = text[..end].to_string();
+
+  // Danish groups thousands with dots - "1.000.000"
+  while text[end..].starts_with('.') && digits_len(&text[end + 1..]) == 3 {
+    integer.push_str(&text[end + 1..end + 4]);
+    end += 4;
+  }
+
+  // A decimal comma, or a decimal point if the thousands were not grouped with dots
+  let grouped = integer.len() < end;
+  let after = &text[end..];
+  let decimals_len = if after.starts_with(',') || (after.starts_with('.') && !grouped && decimal_point) { digits_len(&after[1..]) } else { 0 };
+  if decimals_len > 0 {
+    return (end + 1 + decimals_len, Token::Decimal(format!("{integer}.{}", &after[1..1 + decimals_len])));
+  }
+
+  (end, Token::Integer(integer))
+}
+
+// Returns the text with every number in it replaced by its Danish name
+// "Der var 21 gæster" -> "Der var enogtyve gæster"
+// Thousands may be grouped with dots, and decimals follow a comma - "1.500" -> "et tusind fem hundrede", "1,5" -> "et komma fem"
+// A number with a dot after a definite article is an ordinal - "den 3." -> "den tredje"
+// Without the article the dot might just end a sentence, so "3." is kept as "tre."
+// A date after the article reads its day and month as ordinals - "den 1.1.2020" -> "den første første to tusind og tyve"
+// A sign attached to a number is read out - "+5" -> "plus fem", "-5" -> "minus fem" - but a dash between words is kept
+// Numbers glued to letters, like "12B", are left alone
+pub fn humanize_text(text: &str) -> String {
+  let mut output = String::with_capacity(text.len());
+  let mut rest = text;
+
+  while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+    output.push_str(&rest[..start]);
+    let sign = trailing_sign(&output);
+    let gender = ordinal_article_gender(&output);
+
+    if let (Some(gender), Some((len, day, month, year))) = (gender, scan_date(&rest[start..])) {
+      // A date that cannot be one is kept as it is written, rather than read as numbers with dots in them
+      if !(1..=31).contains(&day) || !(1..=12).contains(&month) {
+        output.push_str(&rest[start..start + len]);
+        rest = &rest[start + len..];
+        continue;
+      }
+
+      output.push_str(&danish_ordinal_name_gendered(day.into(), gender));
+      output.push(' ');
+      output.push_str(&danish_ordinal_name(month.into()));
+      if let Some(year) = year { output.push_str(&format!(" {}", i128::from(year).danish_compound_numeral_name())); }
+
+      // A date without a year ends with the dot of the month, which is part of it like the dot of an ordinal
+      rest = &rest[start + len..];
+      continue;
+    }
+
+    let (len, token) = scan_number(&rest[start..], gender.is_none());
+    let (raw, after) = rest[start..].split_at(len);
+    rest = after;
+
+    // Anything else with dots in it after the article, like "den 3.5", is neither an ordinal nor a decimal, and is kept as written
+    if gender.is_some() && after.starts_with('.') && after[1..].starts_with(|c: char| c.is_ascii_digit()) {
+      let dotted = 1 + after[1..].find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(after.len() - 1);
+      output.push_str(raw);
+      output.push_str(&after[..dotted]);
+      rest = &after[dotted..];
+      continue;
+    }
+
+    let glued = output.chars().next_back().is_some_and(char::is_alphanumeric)
+      || after.chars().next().is_some_and(char::is_alphanumeric);
+    let ordinal = matches!(token, Token::Integer(..))
+      && after.starts_with('.')
+      && after[1..].chars().next().is_none_or(char::is_whitespace)
+      && gender.is_some();
+
+    let name = match token {
+      _ if glued => None,
+      Token::Integer(digits) if ordinal => digits.parse::<i128>().ok().map(|number| danish_ordinal_name_gendered(number, gender.unwrap_or_default())),
+      Token::Integer(digits) => digits.parse::<i128>().ok().map(|number| number.danish_compound_numeral_name()),
+      Token::Decimal(number) => danish_decimal_string(&number, &DanishConfig::default()).ok()
+    };
+
+    match name {
+      // The dot of an ordinal is part of it, and is not kept
+      Some(name) => {
+        if let (Some((sign, word)), false) = (sign, ordinal) {
+          output.truncate(output.len() - sign.len_utf8());
+          output.push_str(word);
+          output.push(' ');
+        }
+        output.push_str(&name);
+        if ordinal { rest = &after[1..]; }
+      }
+      None => output.push_str(raw)
+    }
+  }
+
+  output.push_str(rest);
+  output
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ordinal_dot_after_an_article_is_an_ordinal() {
+    assert_eq!(humanize_text("den 1."), "den første");
+    assert_eq!(humanize_text("det 5. år"), "det femte år");
+    assert_eq!(humanize_text("Det 2. år"), "Det andet år");
+  }
+
+  #[test]
+  fn bare_ordinal_dot_is_kept() {
+    assert_eq!(humanize_text("3."), "tre.");
+  }
+
+  #[test]
+  fn dates_after_an_article_read_day_and_month_as_ordinals() {
+    assert_eq!(humanize_text("den 1.1.2020"), "den første første to tusind og tyve");
+    assert_eq!(humanize_text("Den 24.12. er jul"), "Den fireogtyvende tolvte er jul");
+    assert_eq!(humanize_text("den 1.1.2020."), "den første første to tusind og tyve.");
+    assert_eq!(humanize_text("den 32.1.2020"), "den 32.1.2020");
+  }
+
+  #[test]
+  fn dot_after_an_article_is_not_a_decimal_point() {
+    assert_eq!(humanize_text("3.5 kilo"), "tre komma fem kilo");
+    assert_eq!(humanize_text("den 3.5 kilo"), "den 3.5 kilo");
+    assert_eq!(humanize_text("den 1.000 gang"), "den et tusind gang");
+  }
+
+  #[test]
+  fn signs_attached_to_numbers_are_read() {
+    assert_eq!(humanize_text("+5 grader"), "plus fem grader");
+    assert_eq!(humanize_text("\u{2212}5 grader"), "minus fem grader");
+    assert_eq!(humanize_text("a -5 b"), "a minus fem b");
+  }
+
+  #[test]
+  fn dashes_between_words_are_not_signs() {
+    assert_eq!(humanize_text("København - Aarhus"), "København - Aarhus");
+    assert_eq!(humanize_text("5-6"), "fem-seks");
+  }
+}
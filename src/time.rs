@@ -0,0 +1,135 @@
+//
+// IMPORTS
+//
+
+use std::time::Duration;
+
+use crate::{DanishCompoundNumeral, DanishError, AND, MINUS, NEUTER_ONE, NUMBER_NAMES};
+
+//
+// DANISH LANGUAGE STRINGS
+//
+
+// The units of a duration, largest first - (seconds, singular, plural, neuter)
+// "minut" and "sekund" are neuter, so one of them is "et minut"
+const DURATION_UNITS: &[(u64, &str, &str, bool)] = &[
+  (86_400, "dag", "dage", false),
+  (3_600, "time", "timer", false),
+  (60, "minut", "minutter", true),
+  (1, "sekund", "sekunder", true)
+];
+
+// Spans of minutes with an idiomatic name of their own
+const COLLOQUIAL_MINUTES: &[(i128, &str)] = &[
+  (15, "et kvarter"),
+  (30, "en halv time"),
+  (45, "trekvarter"),
+  (60, "en time"),
+  (90, "halvanden time")
+];
+
+const WEEK: &str = "uge";
+const MAX_WEEK: u8 = 53; // Years starting on a Thursday, and leap years starting on a Wednesday, have 53 ISO weeks
+
+//
+// DURATIONS
+//
+
+// Returns the Danish reading of a duration in whole days, hours, minutes and seconds
+// 150 seconds -> "to minutter og tredive sekunder". Components that are zero are left out, and fractions of a second are dropped
+// A duration of less than a second is "nul sekunder"
+pub fn danish_duration(duration: Duration) -> String {
+  let mut seconds = duration.as_secs();
+  let mut components = vec![];
+
+  for (unit, singular, plural, neuter) in DURATION_UNITS {
+    let count = seconds / unit;
+    seconds %= unit;
+    if count == 0 { continue; }
+
+    components.push(if count == 1 {
+      format!("{} {singular}", if *neuter { NEUTER_ONE } else { NUMBER_NAMES[0][1] })
+    } else {
+      format!("{} {plural}", (count as i128).danish_compound_numeral_name())
+    });
+  }
+
+  // Like a list, the last two components are joined by "og" and the rest by commas
+  match components.split_last() {
+    None => format!("{} {}", NUMBER_NAMES[0][0], DURATION_UNITS[3].2),
+    Some((last, [])) => last.clone(),
+    Some((last, rest)) => format!("{} {AND} {last}", rest.join(", "))
+  }
+}
+
+// Returns the colloquial Danish reading of a span of minutes, 15 -> "et kvarter" and 30 -> "en halv time"
+// Spans without an idiom of their own are just minutes, 20 -> "tyve minutter"
+pub fn danish_colloquial_minutes(minutes: i128) -> String {
+  if let Some((_, name)) = COLLOQUIAL_MINUTES.iter().find(|(span, _)| *span == minutes) { return name.to_string(); }
+
+  let (_, singular, plural, _) = DURATION_UNITS[2];
+  // Minus one minute is singular too, with its sign in front - "minus et minut"
+  if minutes.unsigned_abs() == 1 {
+    let sign = if minutes < 0 { format!("{MINUS} ") } else { String::new() };
+    format!("{sign}{NEUTER_ONE} {singular}")
+  } else {
+    format!("{} {plural}", minutes.danish_compound_numeral_name())
+  }
+}
+
+//
+// CALENDAR
+//
+
+// Returns the Danish reading of an ISO week number, 23 -> "uge treogtyve"
+// Week numbers are 1 to 53, anything else is out of range
+pub fn danish_week(week: u8) -> Result<String, DanishError> {
+  if !(1..=MAX_WEEK).contains(&week) { return Err(DanishError::OutOfRange); }
+  Ok(format!("{WEEK} {}", (week as i128).danish_compound_numeral_name()))
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn durations_name_each_nonzero_component() {
+    assert_eq!(danish_duration(Duration::from_secs(150)), "to minutter og tredive sekunder");
+    assert_eq!(danish_duration(Duration::from_secs(90_061)), "en dag, en time, et minut og et sekund");
+    assert_eq!(danish_duration(Duration::from_secs(86_400)), "en dag");
+  }
+
+  #[test]
+  fn short_durations_are_seconds() {
+    assert_eq!(danish_duration(Duration::from_secs(45)), "femogfyrre sekunder");
+    assert_eq!(danish_duration(Duration::ZERO), "nul sekunder");
+  }
+
+  #[test]
+  fn colloquial_minutes_use_the_idioms() {
+    assert_eq!(danish_colloquial_minutes(15), "et kvarter");
+    assert_eq!(danish_colloquial_minutes(30), "en halv time");
+    assert_eq!(danish_colloquial_minutes(45), "trekvarter");
+  }
+
+  #[test]
+  fn other_minutes_are_counted() {
+    assert_eq!(danish_colloquial_minutes(20), "tyve minutter");
+    assert_eq!(danish_colloquial_minutes(1), "et minut");
+    assert_eq!(danish_colloquial_minutes(-1), "minus et minut");
+    assert_eq!(danish_colloquial_minutes(-20), "minus tyve minutter");
+  }
+
+  #[test]
+  fn weeks_are_numbered_one_to_fifty_three() {
+    assert_eq!(danish_week(1).unwrap(), "uge et");
+    assert_eq!(danish_week(23).unwrap(), "uge treogtyve");
+    assert_eq!(danish_week(53).unwrap(), "uge treoghalvtreds");
+    assert_eq!(danish_week(54), Err(DanishError::OutOfRange));
+    assert_eq!(danish_week(0), Err(DanishError::OutOfRange));
+  }
+}
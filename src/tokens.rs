@@ -0,0 +1,88 @@
+//
+// IMPORTS
+//
+
+use std::fmt;
+
+use crate::{
+  parse::{word_meaning, words, Word},
+  DanishCompoundNumeral, DanishConfig, MINUS
+};
+
+//
+// TOKENS
+//
+
+// A word of a Danish compound numeral name by its role in the name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DanishToken {
+  Minus,                                        // "minus"
+  Word(String),                                 // A number word like "tre", "treogtyve" or a decimal
+  Magnitude { word: String, exponent: u32 },    // "hundrede" or an order of magnitude with its power of ten, "tusind" is 3
+  And,                                          // "og"
+  DecimalSeparator(String)                      // The word before the decimals, "komma" by default
+}
+
+impl fmt::Display for DanishToken {
+  // One token as it is printed by the CLI, "WORD tre", "MAGNITUDE tusind 3" or "AND"
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DanishToken::Minus => write!(f, "MINUS"),
+      DanishToken::Word(word) => write!(f, "WORD {word}"),
+      DanishToken::Magnitude { word, exponent } => write!(f, "MAGNITUDE {word} {exponent}"),
+      DanishToken::And => write!(f, "AND"),
+      DanishToken::DecimalSeparator(word) => write!(f, "DECIMAL {word}")
+    }
+  }
+}
+
+// Returns the words of the Danish compound numeral name of a number as tokens, for seeing how a name is built
+// 1234 -> [WORD et, MAGNITUDE tusind 3, WORD to, MAGNITUDE hundrede 2, AND, WORD fireogtredive]
+pub fn danish_tokens<N: DanishCompoundNumeral>(number: &N, config: &DanishConfig) -> Vec<DanishToken> {
+  let name = number.danish_compound_numeral_name_with(config);
+
+  words(&name).map(|(_, word)| {
+    // The pauses between groups and decimals are not words of their own
+    let word = word.trim_end_matches(',');
+    if word.eq_ignore_ascii_case(MINUS) { return DanishToken::Minus; }
+    if word.eq_ignore_ascii_case(config.decimal_separator) { return DanishToken::DecimalSeparator(word.to_string()); }
+    if word.eq_ignore_ascii_case(config.hundred) { return DanishToken::Magnitude { word: word.to_string(), exponent: 2 }; }
+
+    match word_meaning(word) {
+      Some(Word::And) => DanishToken::And,
+      Some(Word::Hundred) => DanishToken::Magnitude { word: word.to_string(), exponent: 2 },
+      Some(Word::Scale(exponent)) => DanishToken::Magnitude { word: word.to_string(), exponent },
+      _ if word.eq_ignore_ascii_case(config.thousand) => DanishToken::Magnitude { word: word.to_string(), exponent: 3 },
+      _ => DanishToken::Word(word.to_string())
+    }
+  }).collect()
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tokens_follow_the_role_of_each_word() {
+    let tokens = danish_tokens(&-1_234, &DanishConfig::new());
+    assert_eq!(tokens, [
+      DanishToken::Minus,
+      DanishToken::Word("et".to_string()),
+      DanishToken::Magnitude { word: "tusind".to_string(), exponent: 3 },
+      DanishToken::Word("to".to_string()),
+      DanishToken::Magnitude { word: "hundrede".to_string(), exponent: 2 },
+      DanishToken::And,
+      DanishToken::Word("fireogtredive".to_string())
+    ]);
+  }
+
+  #[test]
+  fn decimal_tokens_drop_the_pauses() {
+    let tokens: Vec<String> = danish_tokens(&2.25, &DanishConfig::new()).iter().map(ToString::to_string).collect();
+    assert_eq!(tokens, ["WORD to", "DECIMAL komma", "WORD to", "WORD fem"]);
+  }
+}
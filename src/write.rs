@@ -0,0 +1,69 @@
+//
+// IMPORTS
+//
+
+use std::fmt;
+
+use crate::{DanishCompoundNumeral, DanishConfig};
+
+//
+// WRITING
+//
+
+// Writes the Danish compound numeral name of a number to a fmt::Write, like a String or a Formatter
+pub fn write_danish<W: fmt::Write, N: DanishCompoundNumeral>(writer: &mut W, number: &N) -> fmt::Result {
+  write_danish_with(writer, number, &DanishConfig::default())
+}
+
+// Same as write_danish, but spelled according to the given config
+pub fn write_danish_with<W: fmt::Write, N: DanishCompoundNumeral>(writer: &mut W, number: &N, config: &DanishConfig) -> fmt::Result {
+  writer.write_str(&number.danish_compound_numeral_name_with(config))
+}
+
+// Writes the name of a number like write!, for use in Display impls - write_danish!(f, 42)?
+// A config can be given after the number - write_danish!(f, 42, &config)
+// Like write!, the writer is borrowed through a method call, so a Formatter does not need a mut binding
+#[macro_export]
+macro_rules! write_danish {
+  ($writer:expr, $number:expr) => {
+    $crate::write_danish!($writer, $number, &$crate::DanishConfig::default())
+  };
+  ($writer:expr, $number:expr, $config:expr) => {{
+    // A Formatter has a write_str of its own, and the import is unused for it
+    #[allow(unused_imports)]
+    use ::std::fmt::Write as _;
+    $writer.write_str(&$crate::DanishCompoundNumeral::danish_compound_numeral_name_with(&$number, $config))
+  }};
+}
+
+//
+// TESTS
+//
+
+#[cfg(test)]
+mod tests {
+  use std::fmt;
+
+  use crate::DanishConfig;
+
+  struct Price(i128);
+
+  impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      write_danish!(f, self.0)?;
+      f.write_str(" kroner")
+    }
+  }
+
+  #[test]
+  fn write_danish_works_in_a_display_impl() {
+    assert_eq!(Price(42).to_string(), "toogfyrre kroner");
+  }
+
+  #[test]
+  fn write_danish_takes_a_config() {
+    let mut buf = String::new();
+    write_danish!(buf, 7, &DanishConfig::new().include_numeral(true)).unwrap();
+    assert_eq!(buf, "syv (7)");
+  }
+}
@@ -0,0 +1,72 @@
+//
+// IMPORTS
+//
+
+use std::process::Command;
+
+//
+// CLI
+//
+
+// Runs the binary with the given arguments and returns what it printed, without the final newline
+fn run(args: &[&str]) -> String {
+  let output = Command::new(env!("CARGO_BIN_EXE_danske-tal")).args(args).output().expect("Failed to run the binary");
+  String::from_utf8(output.stdout).expect("Output is not UTF-8").trim_end().to_string()
+}
+
+#[test]
+fn decimals_are_read_digit_by_digit_by_default() {
+  assert_eq!(run(&["3.25"]), "tre komma to, fem");
+}
+
+#[test]
+fn decimal_whole_reads_decimals_as_a_number() {
+  assert_eq!(run(&["--decimal-whole", "3.25"]), "tre komma femogtyve");
+}
+
+#[test]
+fn keep_zeros_reads_leading_zeros_as_digits() {
+  assert_eq!(run(&["--keep-zeros", "007"]), "nul nul syv");
+  assert_eq!(run(&["--keep-zeros", "0"]), "nul");
+  assert_eq!(run(&["007"]), "syv");
+}
+
+#[test]
+fn danish_decimal_comma_is_read_as_a_decimal_point() {
+  assert_eq!(run(&["1,5"]), "et komma fem");
+  assert_eq!(run(&["-1,5"]), "minus et komma fem");
+  assert_eq!(run(&["1,5000"]), "et komma fem");
+}
+
+#[test]
+fn a_comma_that_may_separate_thousands_is_ambiguous() {
+  assert_eq!(run(&["1,500"]), "Invalid input. '1,500' is ambiguous, write the decimals after a dot");
+  assert_eq!(run(&["1,500.25"]), "Invalid input. '1,500.25' is ambiguous, write the decimals after a dot");
+  assert_eq!(run(&["1.5"]), "et komma fem");
+}
+
+#[test]
+fn non_finite_floats_are_read_as_such() {
+  assert_eq!(run(&["inf"]), "uendelig");
+  assert_eq!(run(&["-inf"]), "minus uendelig");
+  assert_eq!(run(&["nan"]), "ikke et tal");
+}
+
+#[test]
+fn uendelig_is_not_read_as_input() {
+  assert_eq!(run(&["uendelig"]), "Invalid input. Expected input of type f64");
+}
+
+#[test]
+fn tokens_are_printed_one_per_line() {
+  assert_eq!(run(&["--tokens", "1234"]), "WORD et\nMAGNITUDE tusind 3\nWORD to\nMAGNITUDE hundrede 2\nAND\nWORD fireogtredive");
+}
+
+#[test]
+fn radix_prefixes_are_read_as_integers() {
+  assert_eq!(run(&["0x2A"]), "toogfyrre");
+  assert_eq!(run(&["0b101010"]), "toogfyrre");
+  assert_eq!(run(&["0o52"]), "toogfyrre");
+  assert_eq!(run(&["0b12"]), "Invalid input. '2' is not a digit of the radix");
+  assert_eq!(run(&["0x100000000000000000000000000000000"]), "Number out of range. 340.282.366.920.938.463.463.374.607.431.768.211.456 is too large to be named");
+}
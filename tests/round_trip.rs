@@ -0,0 +1,44 @@
+//
+// IMPORTS
+//
+
+use danske_tal::{from_danish, DanishCompoundNumeral, DanishConfig, OneForm};
+
+//
+// ROUND TRIPS
+//
+
+// Hand-picked values with the tricky "og" and gender cases
+const VALUES: &[i128] = &[
+  0, 1, 2, 11, 21, 100, 101, 111, 121, 1_001, 1_100, 1_101, 2_001, 101_000, 101_001,
+  1_000_001, 1_001_000, 2_105_000, 7_023_461, i128::MAX, i128::MIN
+];
+
+// Every config the parser reads the names of
+fn configs() -> Vec<DanishConfig> {
+  vec![
+    DanishConfig::new(),
+    DanishConfig::new().single_final_og(true),
+    DanishConfig::new().one_form(OneForm::Common),
+    DanishConfig::new().one_form(OneForm::Neuter),
+    DanishConfig::new().compound_thousands(true)
+  ]
+}
+
+// The curated values, plus every power of ten plus one and their negations - 11, 101, 1001 and so on up to 10^38 + 1
+fn values() -> Vec<i128> {
+  let powers = (1..=38).map(|exponent| 10_i128.pow(exponent) + 1);
+  VALUES.iter().copied().chain(powers.flat_map(|n| [n, -n])).collect()
+}
+
+#[test]
+fn spell_parse_spell_is_idempotent() {
+  for (index, config) in configs().iter().enumerate() {
+    for n in values() {
+      let name = n.danish_compound_numeral_name_with(config);
+      let parsed = from_danish(&name).unwrap_or_else(|error| panic!("config {index}: {name:?} did not parse: {error}"));
+      assert_eq!(parsed, n, "config {index}: {name:?}");
+      assert_eq!(parsed.danish_compound_numeral_name_with(config), name, "config {index}: {n}");
+    }
+  }
+}
@@ -0,0 +1,35 @@
+//
+// IMPORTS
+//
+
+use danske_tal::parse_and_convert;
+
+//
+// TEST VECTORS
+//
+
+// Each line of vectors.tsv is a number and its expected name, separated by a tab
+// Blank lines and lines starting with # are skipped
+const VECTORS: &str = include_str!("vectors.tsv");
+
+#[test]
+fn vectors_match() {
+  let mut failures = Vec::new();
+  for (index, line) in VECTORS.lines().enumerate() {
+    if line.trim().is_empty() || line.starts_with('#') { continue; }
+
+    let line_number = index + 1;
+    let Some((number, expected)) = line.split_once('\t') else {
+      failures.push(format!("vectors.tsv:{line_number}: expected number<TAB>name, found {line:?}"));
+      continue;
+    };
+
+    match parse_and_convert(number) {
+      Ok(name) if name == expected => {}
+      Ok(name) => failures.push(format!("vectors.tsv:{line_number}: {number} is {name:?}, expected {expected:?}")),
+      Err(error) => failures.push(format!("vectors.tsv:{line_number}: {number} failed with {error}"))
+    }
+  }
+
+  assert!(failures.is_empty(), "{} vector(s) failed:\n{}", failures.len(), failures.join("\n"));
+}